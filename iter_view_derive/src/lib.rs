@@ -0,0 +1,230 @@
+//! Derive macros for `iter_view`, use them through the `derive` feature of `iter_view`.
+//!
+//! `#[derive(IterView)]` and `#[derive(IterViewMut)]` forward the impl to one field of a struct. The
+//! field is the only field of the struct, or the field marked with `#[iter_view]`.
+//!
+//! `#[iter_view(map = path::to::fn, item = Type)]` maps each item of the field with the function,
+//! `item` is the result type of the function. The field type must implement the trait without
+//! extra bounds, such as `Vec<T>`, but not a generic parameter `C: IterView`. `map_mut` and
+//! `item_mut` do the same for `IterViewMut`.
+//!
+//! `item` can't be left out: the generated impl must name its `Item` and `Iter` types, and the type
+//! of a function can't be named, so the result type of `map` can't be inferred on stable Rust.
+//! `map` without `item` is a compile error. Item types borrowing from the struct use the lifetime
+//! `'view`, the lifetime of the `&self` borrow in the generated impl, so the struct itself can't
+//! declare a lifetime named `'view`.
+//!
+//! ```rust,ignore
+//! #[derive(IterView)]
+//! struct Roster {
+//!     #[iter_view(map = Member::name, item = &'view str)]
+//!     members: Vec<Member>,
+//!     updated_at: u64,
+//! }
+//! ```
+
+use proc_macro2::{Span, TokenStream};
+use quote::{quote, ToTokens};
+use syn::{parse_macro_input, parse_quote, Data, DeriveInput, Error, Fields, Lifetime, Path, Type};
+
+#[proc_macro_derive(IterView, attributes(iter_view))]
+pub fn derive_iter_view(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(&input, Kind::Ref)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+#[proc_macro_derive(IterViewMut, attributes(iter_view))]
+pub fn derive_iter_view_mut(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(&input, Kind::Mut)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Kind {
+    Ref,
+    Mut,
+}
+
+/// Field selected by `#[iter_view]`, and the projection for the derived trait.
+struct Target {
+    member: TokenStream,
+    ty: Type,
+    map: Option<(Path, Type)>,
+}
+
+#[derive(Default)]
+struct Attr {
+    map: Option<Path>,
+    item: Option<Type>,
+    map_mut: Option<Path>,
+    item_mut: Option<Type>,
+}
+
+fn expand(input: &DeriveInput, kind: Kind) -> syn::Result<TokenStream> {
+    let target = find_target(input, kind)?;
+    let lifetime = Lifetime::new("'view", Span::call_site());
+    if input
+        .generics
+        .lifetimes()
+        .any(|l| l.lifetime.ident == lifetime.ident)
+    {
+        return Err(Error::new_spanned(
+            &input.generics,
+            "lifetime `'view` is reserved by #[derive(IterView)]",
+        ));
+    }
+
+    let (trait_name, method, self_ref, field_ref) = match kind {
+        Kind::Ref => (
+            quote!(IterView),
+            quote!(iter),
+            quote!(&#lifetime self),
+            quote!(&self.),
+        ),
+        Kind::Mut => (
+            quote!(IterViewMut),
+            quote!(iter_mut),
+            quote!(&#lifetime mut self),
+            quote!(&mut self.),
+        ),
+    };
+    let view_trait = quote!(::iter_view::#trait_name<#lifetime>);
+
+    let name = &input.ident;
+    let (_, ty_generics, _) = input.generics.split_for_impl();
+    let mut generics = input.generics.clone();
+    generics.params.insert(0, parse_quote!(#lifetime));
+    let field_ty = &target.ty;
+    // A `FieldTy: IterView` bound hides the `Item` type of the field impl, so the `map` function
+    // can't be coerced to a pointer of it. With `map`, rely on the field impl directly.
+    let bound = match target.map {
+        None => parse_quote!(#field_ty: #view_trait),
+        Some(_) => parse_quote!(#name #ty_generics: #lifetime),
+    };
+    generics.make_where_clause().predicates.push(bound);
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+    let member = &target.member;
+    let field_item = quote!(<#field_ty as #view_trait>::Item);
+    let field_iter = quote!(<#field_ty as #view_trait>::Iter);
+    let call = quote!(::iter_view::#trait_name::#method(#field_ref #member));
+
+    let body = match &target.map {
+        None => quote! {
+            type Item = #field_item;
+            type Iter = #field_iter;
+            fn #method(#self_ref) -> Self::Iter {
+                #call
+            }
+        },
+        Some((map, item)) => quote! {
+            type Item = #item;
+            type Iter = ::core::iter::Map<#field_iter, fn(#field_item) -> #item>;
+            fn #method(#self_ref) -> Self::Iter {
+                let map: fn(#field_item) -> #item = #map;
+                ::core::iter::Iterator::map(#call, map)
+            }
+        },
+    };
+
+    Ok(quote! {
+        impl #impl_generics #view_trait for #name #ty_generics #where_clause {
+            #body
+        }
+    })
+}
+
+fn find_target(input: &DeriveInput, kind: Kind) -> syn::Result<Target> {
+    let Data::Struct(data) = &input.data else {
+        return Err(Error::new_spanned(
+            &input.ident,
+            "#[derive(IterView)] only supports structs",
+        ));
+    };
+    let fields: Vec<_> = match &data.fields {
+        Fields::Named(fields) => fields.named.iter().collect(),
+        Fields::Unnamed(fields) => fields.unnamed.iter().collect(),
+        Fields::Unit => Vec::new(),
+    };
+
+    let mut marked = None;
+    for (index, field) in fields.iter().enumerate() {
+        for attr in field
+            .attrs
+            .iter()
+            .filter(|a| a.path().is_ident("iter_view"))
+        {
+            if marked.is_some() {
+                return Err(Error::new_spanned(
+                    attr,
+                    "only one field can be marked with #[iter_view]",
+                ));
+            }
+            marked = Some((index, parse_attr(attr)?));
+        }
+    }
+    let (index, attr) = match marked {
+        Some(marked) => marked,
+        None if fields.len() == 1 => (0, Attr::default()),
+        None => {
+            return Err(Error::new_spanned(
+                &input.ident,
+                "mark the field to iterate with #[iter_view]",
+            ))
+        }
+    };
+
+    let field = fields[index];
+    let member = match &field.ident {
+        Some(ident) => ident.to_token_stream(),
+        None => syn::Index::from(index).to_token_stream(),
+    };
+    let (map, item, map_key, item_key) = match kind {
+        Kind::Ref => (attr.map, attr.item, "map", "item"),
+        Kind::Mut => (attr.map_mut, attr.item_mut, "map_mut", "item_mut"),
+    };
+    let map = match (map, item) {
+        (Some(map), Some(item)) => Some((map, item)),
+        (None, None) => None,
+        (Some(map), None) => {
+            let msg = format!(
+                "`{map_key}` requires `{item_key}` type, the result type of a function can't be inferred"
+            );
+            return Err(Error::new_spanned(map, msg));
+        }
+        (None, Some(item)) => {
+            let msg = format!("`{item_key}` requires `{map_key}`");
+            return Err(Error::new_spanned(item, msg));
+        }
+    };
+    Ok(Target {
+        member,
+        ty: field.ty.clone(),
+        map,
+    })
+}
+
+fn parse_attr(attr: &syn::Attribute) -> syn::Result<Attr> {
+    let mut r = Attr::default();
+    if matches!(attr.meta, syn::Meta::Path(_)) {
+        return Ok(r);
+    }
+    attr.parse_nested_meta(|meta| {
+        if meta.path.is_ident("map") {
+            r.map = Some(meta.value()?.parse()?);
+        } else if meta.path.is_ident("item") {
+            r.item = Some(meta.value()?.parse()?);
+        } else if meta.path.is_ident("map_mut") {
+            r.map_mut = Some(meta.value()?.parse()?);
+        } else if meta.path.is_ident("item_mut") {
+            r.item_mut = Some(meta.value()?.parse()?);
+        } else {
+            return Err(meta.error("expected `map`, `item`, `map_mut` or `item_mut`"));
+        }
+        Ok(())
+    })?;
+    Ok(r)
+}
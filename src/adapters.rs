@@ -0,0 +1,359 @@
+//! Lazy combinators over views, see [`ViewExt`].
+//!
+//! Unlike `Iterator` adapters, each combinator returns a new view, the result is still re-iterable,
+//! every call of `iter()` starts a fresh pipeline from the underlying view.
+
+use core::iter;
+
+#[cfg(feature = "alloc")]
+use crate::RingWindowsView;
+use crate::{FlatMapView, FlattenView, IndexedView, IterView, MultiMapView};
+
+/// Combinators available on every `IterView`.
+///
+/// Combinators take the view by value, pass a reference to keep using the collection:
+///
+/// ```rust
+/// use iter_view::{IterView, ViewExt};
+///
+/// let v = vec![1, 2, 3, 4];
+/// let evens = (&v).filter_view(|v| **v % 2 == 0).map_view(|v| v * 10);
+/// assert_eq!(evens.iter().collect::<Vec<_>>(), [20, 40]);
+/// assert_eq!(evens.iter().sum::<i32>(), 60);
+/// ```
+pub trait ViewExt<'a>: IterView<'a> + Sized {
+    fn map_view<B, F>(self, f: F) -> MapView<Self, F>
+    where
+        F: Fn(Self::Item) -> B,
+    {
+        MapView { view: self, f }
+    }
+
+    fn filter_view<P>(self, predicate: P) -> FilterView<Self, P>
+    where
+        P: Fn(&Self::Item) -> bool,
+    {
+        FilterView {
+            view: self,
+            predicate,
+        }
+    }
+
+    fn filter_map_view<B, F>(self, f: F) -> FilterMapView<Self, F>
+    where
+        F: Fn(Self::Item) -> Option<B>,
+    {
+        FilterMapView { view: self, f }
+    }
+
+    fn take_view(self, n: usize) -> TakeView<Self> {
+        TakeView { view: self, n }
+    }
+
+    fn skip_view(self, n: usize) -> SkipView<Self> {
+        SkipView { view: self, n }
+    }
+
+    /// Panics if `step` is 0, same as `Iterator::step_by()`.
+    fn step_by_view(self, step: usize) -> StepByView<Self> {
+        assert!(step != 0, "step_by_view() step must not be 0");
+        StepByView { view: self, step }
+    }
+
+    fn chain_view<U>(self, other: U) -> ChainView<Self, U>
+    where
+        U: IterView<'a, Item = Self::Item>,
+    {
+        ChainView { a: self, b: other }
+    }
+
+    fn enumerate_view(self) -> EnumerateView<Self> {
+        EnumerateView { view: self }
+    }
+
+    fn zip_view<U>(self, other: U) -> ZipView<Self, U>
+    where
+        U: IterView<'a>,
+    {
+        ZipView { a: self, b: other }
+    }
+
+    /// Flattens a view of views, such as `&Vec<Vec<T>>`, into a view of the inner items.
+    fn flatten_view<U>(self) -> FlattenView<Self>
+    where
+        Self: IterView<'a, Item = &'a U>,
+        U: IterView<'a> + ?Sized + 'a,
+    {
+        FlattenView::new(self)
+    }
+
+    fn flat_map_view<I, F>(self, f: F) -> FlatMapView<Self, F>
+    where
+        F: Fn(Self::Item) -> I,
+        I: IntoIterator,
+    {
+        FlatMapView::new(self, f)
+    }
+
+    /// Flattens a map of collections, such as `&HashMap<K, Vec<V>>`, into `(key, value)` pairs.
+    fn multi_map_view<K, C>(self) -> MultiMapView<Self>
+    where
+        Self: IterView<'a, Item = (&'a K, &'a C)>,
+        K: ?Sized + 'a,
+        C: IterView<'a> + ?Sized + 'a,
+    {
+        MultiMapView::new(self)
+    }
+
+    fn rev_view(self) -> RevView<Self>
+    where
+        Self::Iter: DoubleEndedIterator,
+    {
+        RevView { view: self }
+    }
+
+    /// Overlapping windows of `size` items, each window is collected to a `Vec`, the previous
+    /// window is kept in a ring buffer so the underlying view is iterated once.
+    ///
+    /// Use [`ContiguousView::windows_view()`](crate::ContiguousView::windows_view) for slices to
+    /// avoid the copies. Panics if `size` is 0.
+    #[cfg(feature = "alloc")]
+    fn ring_windows_view(self, size: usize) -> RingWindowsView<Self>
+    where
+        Self::Item: Clone,
+    {
+        RingWindowsView::new(self, size)
+    }
+}
+
+impl<'a, V: IterView<'a>> ViewExt<'a> for V {}
+
+/// View created by [`ViewExt::map_view()`].
+#[derive(Clone, Copy, Debug)]
+pub struct MapView<V, F> {
+    view: V,
+    f: F,
+}
+
+impl<'a, V, F, B> IterView<'a> for MapView<V, F>
+where
+    V: IterView<'a>,
+    F: Fn(V::Item) -> B + 'a,
+    B: 'a,
+{
+    type Item = B;
+    type Iter = iter::Map<V::Iter, &'a F>;
+    fn iter(&'a self) -> Self::Iter {
+        self.view.iter().map(&self.f)
+    }
+}
+
+impl<'a, V, F, B> IndexedView<'a> for MapView<V, F>
+where
+    V: IndexedView<'a>,
+    F: Fn(V::Item) -> B + 'a,
+    B: 'a,
+{
+    fn get(&'a self, index: usize) -> Option<B> {
+        self.view.get(index).map(&self.f)
+    }
+}
+
+/// View created by [`ViewExt::filter_view()`].
+#[derive(Clone, Copy, Debug)]
+pub struct FilterView<V, P> {
+    view: V,
+    predicate: P,
+}
+
+impl<'a, V, P> IterView<'a> for FilterView<V, P>
+where
+    V: IterView<'a>,
+    P: Fn(&V::Item) -> bool + 'a,
+{
+    type Item = V::Item;
+    type Iter = iter::Filter<V::Iter, &'a P>;
+    fn iter(&'a self) -> Self::Iter {
+        self.view.iter().filter(&self.predicate)
+    }
+}
+
+/// View created by [`ViewExt::filter_map_view()`].
+#[derive(Clone, Copy, Debug)]
+pub struct FilterMapView<V, F> {
+    view: V,
+    f: F,
+}
+
+impl<'a, V, F, B> IterView<'a> for FilterMapView<V, F>
+where
+    V: IterView<'a>,
+    F: Fn(V::Item) -> Option<B> + 'a,
+    B: 'a,
+{
+    type Item = B;
+    type Iter = iter::FilterMap<V::Iter, &'a F>;
+    fn iter(&'a self) -> Self::Iter {
+        self.view.iter().filter_map(&self.f)
+    }
+}
+
+/// View created by [`ViewExt::take_view()`].
+#[derive(Clone, Copy, Debug)]
+pub struct TakeView<V> {
+    view: V,
+    n: usize,
+}
+
+impl<'a, V: IterView<'a>> IterView<'a> for TakeView<V> {
+    type Item = V::Item;
+    type Iter = iter::Take<V::Iter>;
+    fn iter(&'a self) -> Self::Iter {
+        self.view.iter().take(self.n)
+    }
+}
+
+/// View created by [`ViewExt::skip_view()`].
+#[derive(Clone, Copy, Debug)]
+pub struct SkipView<V> {
+    view: V,
+    n: usize,
+}
+
+impl<'a, V: IterView<'a>> IterView<'a> for SkipView<V> {
+    type Item = V::Item;
+    type Iter = iter::Skip<V::Iter>;
+    fn iter(&'a self) -> Self::Iter {
+        self.view.iter().skip(self.n)
+    }
+}
+
+/// View created by [`ViewExt::step_by_view()`].
+#[derive(Clone, Copy, Debug)]
+pub struct StepByView<V> {
+    view: V,
+    step: usize,
+}
+
+impl<'a, V: IterView<'a>> IterView<'a> for StepByView<V> {
+    type Item = V::Item;
+    type Iter = iter::StepBy<V::Iter>;
+    fn iter(&'a self) -> Self::Iter {
+        self.view.iter().step_by(self.step)
+    }
+}
+
+/// View created by [`ViewExt::chain_view()`].
+#[derive(Clone, Copy, Debug)]
+pub struct ChainView<A, B> {
+    a: A,
+    b: B,
+}
+
+impl<'a, A, B> IterView<'a> for ChainView<A, B>
+where
+    A: IterView<'a>,
+    B: IterView<'a, Item = A::Item>,
+{
+    type Item = A::Item;
+    type Iter = iter::Chain<A::Iter, B::Iter>;
+    fn iter(&'a self) -> Self::Iter {
+        self.a.iter().chain(self.b.iter())
+    }
+}
+
+/// View created by [`ViewExt::enumerate_view()`].
+#[derive(Clone, Copy, Debug)]
+pub struct EnumerateView<V> {
+    view: V,
+}
+
+impl<'a, V: IterView<'a>> IterView<'a> for EnumerateView<V> {
+    type Item = (usize, V::Item);
+    type Iter = iter::Enumerate<V::Iter>;
+    fn iter(&'a self) -> Self::Iter {
+        self.view.iter().enumerate()
+    }
+}
+
+/// View created by [`ViewExt::zip_view()`].
+#[derive(Clone, Copy, Debug)]
+pub struct ZipView<A, B> {
+    a: A,
+    b: B,
+}
+
+impl<'a, A, B> IterView<'a> for ZipView<A, B>
+where
+    A: IterView<'a>,
+    B: IterView<'a>,
+{
+    type Item = (A::Item, B::Item);
+    type Iter = iter::Zip<A::Iter, B::Iter>;
+    fn iter(&'a self) -> Self::Iter {
+        self.a.iter().zip(self.b.iter())
+    }
+}
+
+/// View created by [`ViewExt::rev_view()`].
+#[derive(Clone, Copy, Debug)]
+pub struct RevView<V> {
+    view: V,
+}
+
+impl<'a, V> IterView<'a> for RevView<V>
+where
+    V: IterView<'a, Iter: DoubleEndedIterator>,
+{
+    type Item = V::Item;
+    type Iter = iter::Rev<V::Iter>;
+    fn iter(&'a self) -> Self::Iter {
+        self.view.iter().rev()
+    }
+}
+
+impl<'a, V> IndexedView<'a> for RevView<V>
+where
+    V: IndexedView<'a, Iter: DoubleEndedIterator>,
+{
+    fn get(&'a self, index: usize) -> Option<V::Item> {
+        let len = self.view.view_len();
+        if index < len {
+            self.view.get(len - 1 - index)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pipeline() {
+        let v = [1, 2, 3, 4, 5, 6];
+        let view = (&v)
+            .filter_view(|v| **v % 2 == 0)
+            .map_view(|v| v * 10)
+            .chain_view([7, 8].map_view(|v| *v))
+            .skip_view(1)
+            .take_view(3);
+        for _ in 0..2 {
+            assert_eq!(view.iter().collect::<Vec<_>>(), [40, 60, 7]);
+        }
+    }
+
+    #[test]
+    fn enumerate_zip() {
+        let a = ["a", "b", "c", "d"];
+        let b = [1, 2, 3];
+        let view = (&a)
+            .step_by_view(2)
+            .enumerate_view()
+            .zip_view((&b).filter_map_view(|v| (*v != 2).then_some(v * 2)));
+        let expected = [((0, &"a"), 2), ((1, &"c"), 6)];
+        assert_eq!(view.iter().collect::<Vec<_>>(), expected);
+        assert_eq!(view.iter().collect::<Vec<_>>(), expected);
+    }
+}
@@ -0,0 +1,510 @@
+//! Windows and chunks of contiguous collections, see [`ContiguousView`].
+
+#[cfg(feature = "alloc")]
+use alloc::{collections::VecDeque, vec::Vec};
+#[cfg(feature = "alloc")]
+use core::iter::Fuse;
+use core::iter::FusedIterator;
+use core::ops::Range;
+use core::slice;
+
+use crate::{IndexedView, IterView};
+
+/// Collections stored in contiguous memory, or in two contiguous halves like `VecDeque`, that can be
+/// viewed in windows and chunks.
+///
+/// Like the slice methods of the same names, but the results are re-iterable views. Items of the
+/// views are `&'a [T]`, or [`DequeSlice`] for `VecDeque`.
+///
+/// All methods panic if `size` is 0.
+pub trait ContiguousView<'a> {
+    type Slice: 'a;
+
+    fn slice_len(&self) -> usize;
+
+    /// Returns the items in `range`, panics if out of bounds.
+    fn sub_slice(&'a self, range: Range<usize>) -> Self::Slice;
+
+    /// Overlapping windows of `size` items, same as `slice::windows()`.
+    fn windows_view(&'a self, size: usize) -> ChunksView<'a, Self> {
+        ChunksView::new(self, size, Kind::Windows)
+    }
+
+    /// Chunks of `size` items from the start, the last chunk may be shorter, same as
+    /// `slice::chunks()`.
+    fn chunks_view(&'a self, size: usize) -> ChunksView<'a, Self> {
+        ChunksView::new(self, size, Kind::Chunks)
+    }
+
+    /// Chunks of exactly `size` items from the start, the remainder is omitted, same as
+    /// `slice::chunks_exact()`.
+    fn chunks_exact_view(&'a self, size: usize) -> ChunksView<'a, Self> {
+        ChunksView::new(self, size, Kind::ChunksExact)
+    }
+
+    /// Chunks of `size` items from the end, the last chunk may be shorter, same as
+    /// `slice::rchunks()`.
+    fn rchunks_view(&'a self, size: usize) -> ChunksView<'a, Self> {
+        ChunksView::new(self, size, Kind::RChunks)
+    }
+}
+
+impl<'a, T: 'a> ContiguousView<'a> for [T] {
+    type Slice = &'a [T];
+
+    fn slice_len(&self) -> usize {
+        self.len()
+    }
+
+    fn sub_slice(&'a self, range: Range<usize>) -> Self::Slice {
+        &self[range]
+    }
+}
+
+impl<'a, T: 'a, const N: usize> ContiguousView<'a> for [T; N] {
+    type Slice = &'a [T];
+
+    fn slice_len(&self) -> usize {
+        N
+    }
+
+    fn sub_slice(&'a self, range: Range<usize>) -> Self::Slice {
+        &self[range]
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a, T: 'a> ContiguousView<'a> for Vec<T> {
+    type Slice = &'a [T];
+
+    fn slice_len(&self) -> usize {
+        self.len()
+    }
+
+    fn sub_slice(&'a self, range: Range<usize>) -> Self::Slice {
+        &self[range]
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a, T: 'a> ContiguousView<'a> for VecDeque<T> {
+    type Slice = DequeSlice<'a, T>;
+
+    fn slice_len(&self) -> usize {
+        self.len()
+    }
+
+    fn sub_slice(&'a self, range: Range<usize>) -> Self::Slice {
+        DequeSlice::from(self).sub_slice(range)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Kind {
+    Windows,
+    Chunks,
+    ChunksExact,
+    RChunks,
+}
+
+impl Kind {
+    fn count(self, len: usize, size: usize) -> usize {
+        match self {
+            Kind::Windows => len.saturating_sub(size - 1),
+            Kind::Chunks | Kind::RChunks => len.div_ceil(size),
+            Kind::ChunksExact => len / size,
+        }
+    }
+
+    /// Range of the `i`-th window or chunk, `i` must be less than `count()`.
+    fn range(self, len: usize, size: usize, i: usize) -> Range<usize> {
+        match self {
+            Kind::Windows => i..i + size,
+            Kind::Chunks => i * size..i * size + size.min(len - i * size),
+            Kind::ChunksExact => i * size..i * size + size,
+            Kind::RChunks => {
+                let end = len - i * size;
+                end.saturating_sub(size)..end
+            }
+        }
+    }
+}
+
+/// View created by the methods of [`ContiguousView`].
+#[derive(Debug)]
+pub struct ChunksView<'a, C: ?Sized> {
+    collection: &'a C,
+    size: usize,
+    kind: Kind,
+}
+
+impl<'a, C: ?Sized> ChunksView<'a, C> {
+    fn new(collection: &'a C, size: usize, kind: Kind) -> Self {
+        assert!(size != 0, "chunk size must not be 0");
+        Self {
+            collection,
+            size,
+            kind,
+        }
+    }
+}
+
+impl<C: ?Sized> Clone for ChunksView<'_, C> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<C: ?Sized> Copy for ChunksView<'_, C> {}
+
+impl<'s, 'a: 's, C: ContiguousView<'a> + ?Sized> IterView<'s> for ChunksView<'a, C> {
+    type Item = C::Slice;
+    type Iter = ChunksIter<'a, C>;
+    fn iter(&'s self) -> Self::Iter {
+        let len = self.collection.slice_len();
+        ChunksIter {
+            view: *self,
+            len,
+            front: 0,
+            back: self.kind.count(len, self.size),
+        }
+    }
+}
+
+impl<'s, 'a: 's, C: ContiguousView<'a> + ?Sized> IndexedView<'s> for ChunksView<'a, C> {
+    fn get(&'s self, index: usize) -> Option<Self::Item> {
+        let len = self.collection.slice_len();
+        (index < self.kind.count(len, self.size)).then(|| {
+            self.collection
+                .sub_slice(self.kind.range(len, self.size, index))
+        })
+    }
+}
+
+/// Iterator of [`ChunksView`].
+#[derive(Debug)]
+pub struct ChunksIter<'a, C: ?Sized> {
+    view: ChunksView<'a, C>,
+    len: usize,
+    front: usize,
+    back: usize,
+}
+
+impl<C: ?Sized> Clone for ChunksIter<'_, C> {
+    fn clone(&self) -> Self {
+        Self {
+            view: self.view,
+            len: self.len,
+            front: self.front,
+            back: self.back,
+        }
+    }
+}
+
+impl<'a, C: ContiguousView<'a> + ?Sized> ChunksIter<'a, C> {
+    fn chunk(&self, i: usize) -> C::Slice {
+        let range = self.view.kind.range(self.len, self.view.size, i);
+        self.view.collection.sub_slice(range)
+    }
+}
+
+impl<'a, C: ContiguousView<'a> + ?Sized> Iterator for ChunksIter<'a, C> {
+    type Item = C::Slice;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
+        }
+        self.front += 1;
+        Some(self.chunk(self.front - 1))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let n = self.back - self.front;
+        (n, Some(n))
+    }
+}
+
+impl<'a, C: ContiguousView<'a> + ?Sized> DoubleEndedIterator for ChunksIter<'a, C> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
+        }
+        self.back -= 1;
+        Some(self.chunk(self.back))
+    }
+}
+
+impl<'a, C: ContiguousView<'a> + ?Sized> ExactSizeIterator for ChunksIter<'a, C> {}
+
+impl<'a, C: ContiguousView<'a> + ?Sized> FusedIterator for ChunksIter<'a, C> {}
+
+/// Contiguous range of a `VecDeque`, which may span its two halves.
+#[derive(Debug)]
+pub struct DequeSlice<'a, T> {
+    front: &'a [T],
+    back: &'a [T],
+}
+
+impl<T> Clone for DequeSlice<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for DequeSlice<'_, T> {}
+
+impl<'a, T> DequeSlice<'a, T> {
+    /// Returns the two halves, same as `VecDeque::as_slices()`.
+    pub fn as_slices(&self) -> (&'a [T], &'a [T]) {
+        (self.front, self.back)
+    }
+
+    pub fn len(&self) -> usize {
+        self.front.len() + self.back.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the items in `range`, panics if out of bounds.
+    pub fn sub_slice(&self, range: Range<usize>) -> Self {
+        let mid = self.front.len();
+        assert!(
+            range.start <= range.end && range.end <= self.len(),
+            "range {range:?} out of bounds for length {}",
+            self.len()
+        );
+        if range.end <= mid {
+            Self {
+                front: &self.front[range],
+                back: &[],
+            }
+        } else if range.start >= mid {
+            Self {
+                front: &self.back[range.start - mid..range.end - mid],
+                back: &[],
+            }
+        } else {
+            Self {
+                front: &self.front[range.start..],
+                back: &self.back[..range.end - mid],
+            }
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a, T> From<&'a VecDeque<T>> for DequeSlice<'a, T> {
+    fn from(deque: &'a VecDeque<T>) -> Self {
+        let (front, back) = deque.as_slices();
+        Self { front, back }
+    }
+}
+
+impl<'a, T: 'a> ContiguousView<'a> for DequeSlice<'a, T> {
+    type Slice = DequeSlice<'a, T>;
+
+    fn slice_len(&self) -> usize {
+        self.len()
+    }
+
+    fn sub_slice(&'a self, range: Range<usize>) -> Self::Slice {
+        DequeSlice::sub_slice(self, range)
+    }
+}
+
+impl<'s, 'a: 's, T: 'a> IterView<'s> for DequeSlice<'a, T> {
+    type Item = &'a T;
+    type Iter = DequeSliceIter<'a, T>;
+    fn iter(&'s self) -> Self::Iter {
+        DequeSliceIter {
+            front: self.front.iter(),
+            back: self.back.iter(),
+        }
+    }
+}
+
+impl<'s, 'a: 's, T: 'a> IndexedView<'s> for DequeSlice<'a, T> {
+    fn get(&'s self, index: usize) -> Option<&'a T> {
+        match index.checked_sub(self.front.len()) {
+            None => self.front.get(index),
+            Some(i) => self.back.get(i),
+        }
+    }
+}
+
+/// Iterator of [`DequeSlice`].
+#[derive(Clone, Debug)]
+pub struct DequeSliceIter<'a, T> {
+    front: slice::Iter<'a, T>,
+    back: slice::Iter<'a, T>,
+}
+
+impl<'a, T> Iterator for DequeSliceIter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.front.next().or_else(|| self.back.next())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let n = self.front.len() + self.back.len();
+        (n, Some(n))
+    }
+}
+
+impl<T> DoubleEndedIterator for DequeSliceIter<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.back.next_back().or_else(|| self.front.next_back())
+    }
+}
+
+impl<T> ExactSizeIterator for DequeSliceIter<'_, T> {}
+
+impl<T> FusedIterator for DequeSliceIter<'_, T> {}
+
+/// View created by [`ViewExt::ring_windows_view()`](crate::ViewExt::ring_windows_view).
+#[cfg(feature = "alloc")]
+#[derive(Clone, Copy, Debug)]
+pub struct RingWindowsView<V> {
+    view: V,
+    size: usize,
+}
+
+#[cfg(feature = "alloc")]
+impl<V> RingWindowsView<V> {
+    pub(crate) fn new(view: V, size: usize) -> Self {
+        assert!(size != 0, "window size must not be 0");
+        Self { view, size }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a, V> IterView<'a> for RingWindowsView<V>
+where
+    V: IterView<'a, Item: Clone>,
+{
+    type Item = Vec<V::Item>;
+    type Iter = RingWindowsIter<V::Iter>;
+    fn iter(&'a self) -> Self::Iter {
+        RingWindowsIter {
+            iter: self.view.iter().fuse(),
+            ring: VecDeque::with_capacity(self.size),
+            size: self.size,
+        }
+    }
+}
+
+/// Iterator of [`RingWindowsView`], keeps the last window in a ring buffer.
+#[cfg(feature = "alloc")]
+pub struct RingWindowsIter<I: Iterator> {
+    iter: Fuse<I>,
+    ring: VecDeque<I::Item>,
+    size: usize,
+}
+
+#[cfg(feature = "alloc")]
+impl<I: Iterator<Item: Clone>> Iterator for RingWindowsIter<I> {
+    type Item = Vec<I::Item>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.ring.len() == self.size {
+            self.ring.pop_front();
+        }
+        while self.ring.len() < self.size {
+            self.ring.push_back(self.iter.next()?);
+        }
+        Some(self.ring.iter().cloned().collect())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<I: Iterator<Item: Clone>> FusedIterator for RingWindowsIter<I> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn slice_chunks() {
+        let v = [1, 2, 3, 4, 5];
+        let view = v.windows_view(3);
+        for _ in 0..2 {
+            let windows: Vec<_> = view.iter().collect();
+            assert_eq!(windows, [&[1, 2, 3][..], &[2, 3, 4], &[3, 4, 5]]);
+        }
+        assert_eq!(view.iter().next_back(), Some(&[3, 4, 5][..]));
+        let chunks: Vec<_> = v.chunks_view(2).iter().collect();
+        assert_eq!(chunks, [&[1, 2][..], &[3, 4], &[5]]);
+        let chunks: Vec<_> = v.chunks_exact_view(2).iter().collect();
+        assert_eq!(chunks, [&[1, 2][..], &[3, 4]]);
+        let chunks: Vec<_> = [1, 2, 3].rchunks_view(2).iter().collect();
+        assert_eq!(chunks, [&[2, 3][..], &[1]]);
+        assert_eq!([1u8; 2].windows_view(3).iter().len(), 0);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn zero_sized_items() {
+        let v = vec![(); usize::MAX];
+        assert_eq!(v.windows_view(1).iter().len(), usize::MAX);
+        assert_eq!(
+            v.windows_view(2).iter().next_back().map(<[()]>::len),
+            Some(2)
+        );
+        let chunks = v.chunks_view(2);
+        assert_eq!(chunks.iter().len(), usize::MAX / 2 + 1);
+        assert_eq!(chunks.iter().next_back().map(<[()]>::len), Some(1));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn deque_chunks() {
+        let mut d: VecDeque<_> = [3, 4, 5].into_iter().collect();
+        d.push_front(2);
+        d.push_front(1);
+        assert!(!d.as_slices().1.is_empty());
+
+        let view = d.windows_view(2);
+        let windows: Vec<Vec<_>> = view.iter().map(|w| w.iter().copied().collect()).collect();
+        assert_eq!(windows, [[1, 2], [2, 3], [3, 4], [4, 5]]);
+        let chunks: Vec<Vec<_>> = d
+            .rchunks_view(2)
+            .iter()
+            .map(|w| w.iter().rev().copied().collect())
+            .collect();
+        assert_eq!(chunks, [vec![5, 4], vec![3, 2], vec![1]]);
+        let chunk = view.get(1).unwrap();
+        assert_eq!(chunk.len(), 2);
+        assert_eq!(IndexedView::get(&chunk, 1), Some(&3));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn ring_windows() {
+        use crate::ViewExt;
+
+        let s: std::collections::BTreeSet<_> = [4, 1, 3, 2].into_iter().collect();
+        let view = (&s).ring_windows_view(3);
+        assert_eq!(
+            view.iter().collect::<Vec<_>>(),
+            [[&1, &2, &3], [&2, &3, &4]]
+        );
+        assert_eq!((&s).ring_windows_view(5).iter().count(), 0);
+
+        // Yields 1, 2, None, 4, 5, None, ...
+        let unfused = crate::from_fn(|| {
+            let mut n = 0;
+            core::iter::from_fn(move || {
+                n += 1;
+                (n % 3 != 0).then_some(n)
+            })
+        });
+        let mut iter = unfused.ring_windows_view(2).iter();
+        assert_eq!(iter.next(), Some(vec![1, 2]));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next(), None);
+    }
+}
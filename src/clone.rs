@@ -0,0 +1,157 @@
+//! Views over iterators that are cheap to clone, see [`CloneView`].
+
+use core::ops::{Range, RangeInclusive};
+
+use crate::{GatIterView, IterView};
+
+/// Wraps a `Clone` iterator as a view, each `iter()` returns a clone of the iterator, which starts
+/// from where the wrapped iterator is.
+///
+/// ```rust
+/// use iter_view::{IntoView, IterView};
+///
+/// let words = "a b c".split(' ').into_view();
+/// assert_eq!(words.iter().count(), 3);
+/// assert_eq!(words.iter().last(), Some("c"));
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct CloneView<I> {
+    iter: I,
+}
+
+impl<I: Iterator + Clone> CloneView<I> {
+    pub fn new(iter: I) -> Self {
+        Self { iter }
+    }
+
+    pub fn into_inner(self) -> I {
+        self.iter
+    }
+}
+
+impl<'a, I> IterView<'a> for CloneView<I>
+where
+    I: Iterator<Item: 'a> + Clone,
+{
+    type Item = I::Item;
+    type Iter = I;
+    fn iter(&'a self) -> Self::Iter {
+        self.iter.clone()
+    }
+}
+
+impl<I: Iterator + Clone> GatIterView for CloneView<I> {
+    type Item<'a>
+        = I::Item
+    where
+        Self: 'a;
+    type Iter<'a>
+        = I
+    where
+        Self: 'a;
+    fn gat_iter(&self) -> Self::Iter<'_> {
+        self.iter.clone()
+    }
+}
+
+/// Converts a `Clone` iterator into a [`CloneView`], implemented for every such iterator.
+pub trait IntoView: Iterator + Clone + Sized {
+    fn into_view(self) -> CloneView<Self> {
+        CloneView::new(self)
+    }
+}
+
+impl<I: Iterator + Clone> IntoView for I {}
+
+impl<'a, A> IterView<'a> for Range<A>
+where
+    A: 'a,
+    Range<A>: Iterator<Item = A> + Clone,
+{
+    type Item = A;
+    type Iter = Range<A>;
+    fn iter(&'a self) -> Self::Iter {
+        self.clone()
+    }
+}
+
+impl<'a, A> IterView<'a> for RangeInclusive<A>
+where
+    A: 'a,
+    RangeInclusive<A>: Iterator<Item = A> + Clone,
+{
+    type Item = A;
+    type Iter = RangeInclusive<A>;
+    fn iter(&'a self) -> Self::Iter {
+        self.clone()
+    }
+}
+
+impl<A> GatIterView for Range<A>
+where
+    Range<A>: Iterator<Item = A> + Clone,
+{
+    type Item<'a>
+        = A
+    where
+        Self: 'a;
+    type Iter<'a>
+        = Range<A>
+    where
+        Self: 'a;
+    fn gat_iter(&self) -> Self::Iter<'_> {
+        self.clone()
+    }
+}
+
+impl<A> GatIterView for RangeInclusive<A>
+where
+    RangeInclusive<A>: Iterator<Item = A> + Clone,
+{
+    type Item<'a>
+        = A
+    where
+        Self: 'a;
+    type Iter<'a>
+        = RangeInclusive<A>
+    where
+        Self: 'a;
+    fn gat_iter(&self) -> Self::Iter<'_> {
+        self.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ExactSizeIterView, ViewExt};
+
+    fn sum<'a, V: IterView<'a, Item = u32>>(v: &'a V) -> u32 {
+        v.iter().sum()
+    }
+
+    #[test]
+    fn clone_view() {
+        let v = [1, 2, 3];
+        let view = v.iter().map(|v| v * 2).into_view();
+        assert_eq!(sum(&view), 12);
+        assert_eq!(sum(&view), 12);
+        assert_eq!(view.view_len(), 3);
+
+        let chars = "héllo".chars().into_view();
+        assert_eq!(chars.gat_iter().nth(1), Some('é'));
+        assert_eq!(chars.into_inner().count(), 5);
+    }
+
+    #[test]
+    fn ranges() {
+        assert_eq!(sum(&(0..4)), 6);
+        assert_eq!(sum(&(1..=4)), 10);
+        let view = (0..10).filter_view(|v| v % 3 == 0);
+        assert_eq!(view.iter().collect::<Vec<_>>(), [0, 3, 6, 9]);
+        assert_eq!((0..5u8).view_len(), 5);
+        // Don't clash with `ExactSizeIterator` methods of ranges.
+        assert_eq!((0..5u8).len(), 5);
+        assert!((0..0u8).view_is_empty());
+    }
+}
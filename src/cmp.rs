@@ -0,0 +1,188 @@
+//! Compare and hash views by content, items are compared in iteration order.
+
+use core::cmp::Ordering;
+use core::hash::{Hash, Hasher};
+
+use crate::IterView;
+
+/// Returns true if the two views yield equal items in the same order.
+pub fn view_eq<'a, 'b, A, B>(a: &'a A, b: &'b B) -> bool
+where
+    A: IterView<'a> + ?Sized,
+    B: IterView<'b> + ?Sized,
+    A::Item: PartialEq<B::Item>,
+{
+    a.iter().eq(b.iter())
+}
+
+/// Lexicographically compares the items of two views.
+pub fn view_partial_cmp<'a, 'b, A, B>(a: &'a A, b: &'b B) -> Option<Ordering>
+where
+    A: IterView<'a> + ?Sized,
+    B: IterView<'b> + ?Sized,
+    A::Item: PartialOrd<B::Item>,
+{
+    a.iter().partial_cmp(b.iter())
+}
+
+/// Lexicographically compares the items of two views.
+pub fn view_cmp<'a, A, B>(a: &'a A, b: &'a B) -> Ordering
+where
+    A: IterView<'a> + ?Sized,
+    B: IterView<'a, Item = A::Item> + ?Sized,
+    A::Item: Ord,
+{
+    a.iter().cmp(b.iter())
+}
+
+/// Feeds the items of the view and their count into `state`, views equal by [`view_eq()`] have
+/// the same hash.
+pub fn view_hash<'a, V, H>(v: &'a V, state: &mut H)
+where
+    V: IterView<'a> + ?Sized,
+    V::Item: Hash,
+    H: Hasher,
+{
+    let mut n = 0usize;
+    for item in v.iter() {
+        item.hash(state);
+        n += 1;
+    }
+    state.write_usize(n);
+}
+
+/// Returns true if the two views yield the same items with the same number of occurrences, in any
+/// order.
+#[cfg(feature = "std")]
+pub fn multiset_eq<'a, A, B>(a: &'a A, b: &'a B) -> bool
+where
+    A: IterView<'a> + ?Sized,
+    B: IterView<'a, Item = A::Item> + ?Sized,
+    A::Item: Eq + Hash,
+{
+    let mut counts = std::collections::HashMap::<A::Item, usize>::new();
+    for item in a.iter() {
+        *counts.entry(item).or_default() += 1;
+    }
+    for item in b.iter() {
+        match counts.get_mut(&item) {
+            Some(n) if *n > 0 => *n -= 1,
+            _ => return false,
+        }
+    }
+    counts.values().all(|n| *n == 0)
+}
+
+/// Implements `PartialEq`, `Eq`, `PartialOrd`, `Ord` and `Hash` by the content of the view, such
+/// as to use views as map keys.
+///
+/// The view must be an `IterView` for every lifetime, such as an owned collection of `'static`
+/// items. Items are compared in iteration order, use views with a deterministic order.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ByContent<V>(pub V);
+
+impl<V> PartialEq for ByContent<V>
+where
+    V: for<'a> IterView<'a>,
+    for<'a> <V as IterView<'a>>::Item: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        view_eq(&self.0, &other.0)
+    }
+}
+
+impl<V> Eq for ByContent<V>
+where
+    V: for<'a> IterView<'a>,
+    for<'a> <V as IterView<'a>>::Item: Eq,
+{
+}
+
+impl<V> PartialOrd for ByContent<V>
+where
+    V: for<'a> IterView<'a>,
+    for<'a> <V as IterView<'a>>::Item: PartialOrd,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        view_partial_cmp(&self.0, &other.0)
+    }
+}
+
+impl<V> Ord for ByContent<V>
+where
+    V: for<'a> IterView<'a>,
+    for<'a> <V as IterView<'a>>::Item: Ord,
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        view_cmp(&self.0, &other.0)
+    }
+}
+
+impl<V> Hash for ByContent<V>
+where
+    V: for<'a> IterView<'a>,
+    for<'a> <V as IterView<'a>>::Item: Hash,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        view_hash(&self.0, state)
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use super::*;
+    use std::hash::DefaultHasher;
+
+    fn hash_of<'a, V: IterView<'a, Item: Hash> + ?Sized>(v: &'a V) -> u64 {
+        let mut h = DefaultHasher::new();
+        view_hash(v, &mut h);
+        h.finish()
+    }
+
+    #[test]
+    fn eq_cmp_hash() {
+        use std::collections::{LinkedList, VecDeque};
+
+        let a: VecDeque<_> = [1, 2, 3].into_iter().collect();
+        let b = [1, 2, 3];
+        assert!(view_eq(&a, &b));
+        assert!(!view_eq(&a, &b[..2]));
+        assert_eq!(view_cmp(&a, &b[..2]), Ordering::Greater);
+        assert_eq!(view_partial_cmp(&[1.0], &[f64::NAN]), None);
+        assert_eq!(hash_of(&a), hash_of(&b));
+        let l: LinkedList<_> = [1, 2].into_iter().collect();
+        assert_ne!(hash_of(&l), hash_of(&b));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn multiset() {
+        use std::collections::{BTreeSet, HashSet};
+
+        let a: HashSet<_> = (0..100).collect();
+        let b: BTreeSet<_> = (0..100).rev().collect();
+        assert!(multiset_eq(&a, &b));
+        assert!(multiset_eq(&[1, 2, 1], &vec![1, 1, 2]));
+        assert!(!multiset_eq(&[1, 2, 1], &vec![1, 2, 2]));
+        assert!(!multiset_eq(&[1, 2], &vec![1, 2, 2]));
+        assert!(!multiset_eq(&[1, 2, 2], &vec![1, 2]));
+    }
+
+    #[test]
+    fn by_content() {
+        use std::collections::VecDeque;
+
+        let mut m = std::collections::HashMap::new();
+        m.insert(ByContent(vec![1, 2]), "vec");
+        let key: VecDeque<_> = [1, 2].into_iter().collect();
+        assert_eq!(
+            m.get(&ByContent(key.iter().copied().collect())),
+            Some(&"vec")
+        );
+        assert!(ByContent(vec!["a"]) < ByContent(vec!["a", "b"]));
+        assert_eq!(
+            ByContent(VecDeque::from([1])),
+            ByContent(VecDeque::from([1]))
+        );
+    }
+}
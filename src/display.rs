@@ -0,0 +1,294 @@
+//! `Display` and `Debug` adapters for views, see [`DisplayViewExt`].
+//!
+//! Views are re-iterable, so the adapters format the items directly, and can be formatted many
+//! times without buffering.
+
+use core::fmt::{self, Debug, Display, Formatter};
+
+use crate::IterView;
+
+/// Format adapters available on every `IterView`.
+///
+/// ```rust
+/// use iter_view::DisplayViewExt;
+///
+/// let v: Vec<_> = (1..=100).collect();
+/// let s = v.display_with(", ").prefix("[").suffix("]").limit(3).to_string();
+/// assert_eq!(s, "[1, 2, 3, … (+97 more)]");
+/// ```
+pub trait DisplayViewExt<'a>: IterView<'a> {
+    /// Format items separated by `sep`.
+    fn display_with(&'a self, sep: &'a str) -> DisplayView<'a, Self> {
+        DisplayView {
+            view: self,
+            layout: Layout {
+                sep,
+                ..Layout::default()
+            },
+        }
+    }
+
+    /// Format `(key, value)` items like a map: `{k1: v1, k2: v2}`.
+    fn display_map(&'a self) -> DisplayMapView<'a, Self> {
+        DisplayMapView {
+            view: self,
+            layout: Layout {
+                sep: ", ",
+                prefix: "{",
+                suffix: "}",
+                limit: None,
+            },
+            kv_sep: ": ",
+        }
+    }
+}
+
+impl<'a, V: IterView<'a> + ?Sized> DisplayViewExt<'a> for V {}
+
+#[derive(Clone, Copy, Debug, Default)]
+struct Layout<'a> {
+    sep: &'a str,
+    prefix: &'a str,
+    suffix: &'a str,
+    limit: Option<usize>,
+}
+
+impl Layout<'_> {
+    fn write<I: Iterator>(
+        &self,
+        f: &mut Formatter<'_>,
+        mut iter: I,
+        mut write_item: impl FnMut(&mut Formatter<'_>, I::Item) -> fmt::Result,
+    ) -> fmt::Result {
+        let limit = self.limit.unwrap_or(usize::MAX);
+        f.write_str(self.prefix)?;
+        let mut written = 0;
+        for item in iter.by_ref().take(limit) {
+            if written > 0 {
+                f.write_str(self.sep)?;
+            }
+            write_item(f, item)?;
+            written += 1;
+        }
+        // Iterator ended before the limit, don't pull again, it may not be fused.
+        if written < limit {
+            return f.write_str(self.suffix);
+        }
+        // Count the rest only if the iterator knows it, the view may be large or unbounded.
+        let rest = match iter.size_hint() {
+            (lo, Some(hi)) if lo == hi => Some(lo),
+            _ => None,
+        };
+        let more = match rest {
+            Some(rest) => rest > 0,
+            None => iter.next().is_some(),
+        };
+        if more {
+            if limit > 0 {
+                f.write_str(self.sep)?;
+            }
+            match rest {
+                Some(rest) => write!(f, "… (+{rest} more)")?,
+                None => f.write_str("…")?,
+            }
+        }
+        f.write_str(self.suffix)
+    }
+}
+
+/// Formats items of a view, created by [`DisplayViewExt::display_with()`].
+///
+/// Implements `Display` if items implement `Display`, and `Debug` if items implement `Debug`,
+/// formatter flags such as width and precision apply to each item.
+pub struct DisplayView<'a, V: ?Sized> {
+    view: &'a V,
+    layout: Layout<'a>,
+}
+
+impl<'a, V: ?Sized> DisplayView<'a, V> {
+    pub fn prefix(mut self, prefix: &'a str) -> Self {
+        self.layout.prefix = prefix;
+        self
+    }
+
+    pub fn suffix(mut self, suffix: &'a str) -> Self {
+        self.layout.suffix = suffix;
+        self
+    }
+
+    /// Format at most `limit` items, the rest are summarized as `… (+N more)`, or `…` if the
+    /// iterator of the view doesn't know its exact length.
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.layout.limit = Some(limit);
+        self
+    }
+}
+
+impl<V: ?Sized> Clone for DisplayView<'_, V> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<V: ?Sized> Copy for DisplayView<'_, V> {}
+
+impl<'a, V> Display for DisplayView<'a, V>
+where
+    V: IterView<'a> + ?Sized,
+    V::Item: Display,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.layout
+            .write(f, self.view.iter(), |f, item| Display::fmt(&item, f))
+    }
+}
+
+impl<'a, V> Debug for DisplayView<'a, V>
+where
+    V: IterView<'a> + ?Sized,
+    V::Item: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.layout
+            .write(f, self.view.iter(), |f, item| Debug::fmt(&item, f))
+    }
+}
+
+/// Formats `(key, value)` items of a view, created by [`DisplayViewExt::display_map()`].
+pub struct DisplayMapView<'a, V: ?Sized> {
+    view: &'a V,
+    layout: Layout<'a>,
+    kv_sep: &'a str,
+}
+
+impl<'a, V: ?Sized> DisplayMapView<'a, V> {
+    /// Separator between entries, default to `", "`.
+    pub fn sep(mut self, sep: &'a str) -> Self {
+        self.layout.sep = sep;
+        self
+    }
+
+    /// Separator between key and value, default to `": "`.
+    pub fn kv_sep(mut self, kv_sep: &'a str) -> Self {
+        self.kv_sep = kv_sep;
+        self
+    }
+
+    /// Default to `"{"`.
+    pub fn prefix(mut self, prefix: &'a str) -> Self {
+        self.layout.prefix = prefix;
+        self
+    }
+
+    /// Default to `"}"`.
+    pub fn suffix(mut self, suffix: &'a str) -> Self {
+        self.layout.suffix = suffix;
+        self
+    }
+
+    /// Format at most `limit` entries, the rest are summarized like [`DisplayView::limit()`].
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.layout.limit = Some(limit);
+        self
+    }
+}
+
+impl<V: ?Sized> Clone for DisplayMapView<'_, V> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<V: ?Sized> Copy for DisplayMapView<'_, V> {}
+
+impl<'a, V, K, T> Display for DisplayMapView<'a, V>
+where
+    V: IterView<'a, Item = (K, T)> + ?Sized,
+    K: Display,
+    T: Display,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.layout.write(f, self.view.iter(), |f, (k, v)| {
+            Display::fmt(&k, f)?;
+            f.write_str(self.kv_sep)?;
+            Display::fmt(&v, f)
+        })
+    }
+}
+
+impl<'a, V, K, T> Debug for DisplayMapView<'a, V>
+where
+    V: IterView<'a, Item = (K, T)> + ?Sized,
+    K: Debug,
+    T: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.layout.write(f, self.view.iter(), |f, (k, v)| {
+            Debug::fmt(&k, f)?;
+            f.write_str(self.kv_sep)?;
+            Debug::fmt(&v, f)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ViewExt;
+
+    #[test]
+    fn display() {
+        let v = [1.5, 2.25];
+        let d = v.display_with(" | ");
+        assert_eq!(d.to_string(), "1.5 | 2.25");
+        assert_eq!(format!("{d:.1}"), "1.5 | 2.2");
+        assert_eq!(
+            format!("{:?}", ["a", "b"].display_with(", ")),
+            r#""a", "b""#
+        );
+        assert_eq!([0i32; 0].display_with(", ").to_string(), "");
+    }
+
+    #[test]
+    fn limit() {
+        let v = [1, 2, 3];
+        let d = v.display_with(", ").prefix("[").suffix("]");
+        assert_eq!(d.limit(2).to_string(), "[1, 2, … (+1 more)]");
+        assert_eq!(d.limit(3).to_string(), "[1, 2, 3]");
+        assert_eq!(d.limit(0).to_string(), "[… (+3 more)]");
+
+        let odd = v.filter_view(|v| *v % 2 == 1);
+        assert_eq!(odd.display_with(", ").limit(1).to_string(), "1, …");
+        assert_eq!(odd.display_with(", ").limit(2).to_string(), "1, 3");
+
+        // Yields 1, 2, None, 4, 5, None, ...
+        let unfused = crate::from_fn(|| {
+            let mut n = 0;
+            core::iter::from_fn(move || {
+                n += 1;
+                (n % 3 != 0).then_some(n)
+            })
+        });
+        assert_eq!(unfused.display_with(",").to_string(), "1,2");
+        assert_eq!(unfused.display_with(",").limit(1).to_string(), "1,…");
+        assert_eq!(unfused.display_with(",").limit(3).to_string(), "1,2");
+
+        let unbounded = crate::from_fn(|| 0u64..);
+        assert_eq!(
+            unbounded.display_with(", ").limit(3).to_string(),
+            "0, 1, 2, …"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn map() {
+        use std::collections::BTreeMap;
+
+        let m: BTreeMap<_, _> = [("a", 1), ("b", 2)].into_iter().collect();
+        assert_eq!(m.display_map().to_string(), "{a: 1, b: 2}");
+        assert_eq!(format!("{:?}", m.display_map()), r#"{"a": 1, "b": 2}"#);
+        let d = m.display_map().kv_sep("=").sep("&").prefix("").suffix("");
+        assert_eq!(d.limit(1).to_string(), "a=1&… (+1 more)");
+    }
+}
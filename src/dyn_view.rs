@@ -0,0 +1,204 @@
+//! Object safe flavor of [`IterView`].
+
+use alloc::boxed::Box;
+
+use crate::IterView;
+
+/// Object safe companion of `IterView`, the iterator is boxed, so views backed by different
+/// collections can be stored as the same `dyn DynIterView<'a, Item>` type.
+///
+/// Implemented for every `IterView` automatically, and `dyn DynIterView` implements `IterView`, the
+/// two can be used interchangeably.
+///
+/// `dyn DynIterView<'a, Item>` must be borrowed for `'a` to iterate, a `Box<dyn DynIterView<'a, _>>`
+/// owned by a local or a struct can't be, so store it by reference. Use [`DynRefIterView`] or
+/// [`DynPairIterView`] to store owned collections, such as `Box<dyn DynRefIterView<T>>`.
+pub trait DynIterView<'a, Item> {
+    fn dyn_iter(&'a self) -> Box<dyn Iterator<Item = Item> + 'a>;
+}
+
+impl<'a, V> DynIterView<'a, V::Item> for V
+where
+    V: IterView<'a> + ?Sized,
+    V::Iter: 'a,
+{
+    fn dyn_iter(&'a self) -> Box<dyn Iterator<Item = V::Item> + 'a> {
+        Box::new(self.iter())
+    }
+}
+
+impl<'a, Item: 'a> IterView<'a> for dyn DynIterView<'a, Item> + 'a {
+    type Item = Item;
+    type Iter = Box<dyn Iterator<Item = Item> + 'a>;
+    fn iter(&'a self) -> Self::Iter {
+        self.dyn_iter()
+    }
+}
+
+impl<'a, Item: 'a> IterView<'a> for dyn DynIterView<'a, Item> + Send + 'a {
+    type Item = Item;
+    type Iter = Box<dyn Iterator<Item = Item> + 'a>;
+    fn iter(&'a self) -> Self::Iter {
+        self.dyn_iter()
+    }
+}
+
+impl<'a, Item: 'a> IterView<'a> for dyn DynIterView<'a, Item> + Send + Sync + 'a {
+    type Item = Item;
+    type Iter = Box<dyn Iterator<Item = Item> + 'a>;
+    fn iter(&'a self) -> Self::Iter {
+        self.dyn_iter()
+    }
+}
+
+/// Object safe view of `&T` items that doesn't borrow itself for a fixed lifetime, so it can be stored
+/// owned, such as `Box<dyn DynRefIterView<T>>`.
+///
+/// Implemented for every view that is a `DynIterView<'s, &'s T>` for any lifetime `'s`, such as
+/// `Vec<T>` with `'static` `T`.
+pub trait DynRefIterView<T: ?Sized> {
+    fn dyn_ref_iter(&self) -> Box<dyn Iterator<Item = &T> + '_>;
+}
+
+impl<T: ?Sized, V> DynRefIterView<T> for V
+where
+    V: for<'s> DynIterView<'s, &'s T> + ?Sized,
+{
+    fn dyn_ref_iter(&self) -> Box<dyn Iterator<Item = &T> + '_> {
+        self.dyn_iter()
+    }
+}
+
+impl<'a, T: ?Sized + 'a> IterView<'a> for dyn DynRefIterView<T> + '_ {
+    type Item = &'a T;
+    type Iter = Box<dyn Iterator<Item = &'a T> + 'a>;
+    fn iter(&'a self) -> Self::Iter {
+        self.dyn_ref_iter()
+    }
+}
+
+impl<'a, T: ?Sized + 'a> IterView<'a> for dyn DynRefIterView<T> + Send + '_ {
+    type Item = &'a T;
+    type Iter = Box<dyn Iterator<Item = &'a T> + 'a>;
+    fn iter(&'a self) -> Self::Iter {
+        self.dyn_ref_iter()
+    }
+}
+
+impl<'a, T: ?Sized + 'a> IterView<'a> for dyn DynRefIterView<T> + Send + Sync + '_ {
+    type Item = &'a T;
+    type Iter = Box<dyn Iterator<Item = &'a T> + 'a>;
+    fn iter(&'a self) -> Self::Iter {
+        self.dyn_ref_iter()
+    }
+}
+
+/// Object safe view of `(&K, &V)` items that doesn't borrow itself for a fixed lifetime, the map
+/// flavor of [`DynRefIterView`], such as `Box<dyn DynPairIterView<K, V>>`.
+///
+/// Implemented for every view that is a `DynIterView<'s, (&'s K, &'s V)>` for any lifetime `'s`,
+/// such as `HashMap<K, V>` with `'static` `K` and `V`.
+pub trait DynPairIterView<K: ?Sized, V: ?Sized> {
+    fn dyn_pair_iter(&self) -> Box<dyn Iterator<Item = (&K, &V)> + '_>;
+}
+
+impl<K: ?Sized, V: ?Sized, C> DynPairIterView<K, V> for C
+where
+    C: for<'s> DynIterView<'s, (&'s K, &'s V)> + ?Sized,
+{
+    fn dyn_pair_iter(&self) -> Box<dyn Iterator<Item = (&K, &V)> + '_> {
+        self.dyn_iter()
+    }
+}
+
+impl<'a, K: ?Sized + 'a, V: ?Sized + 'a> IterView<'a> for dyn DynPairIterView<K, V> + '_ {
+    type Item = (&'a K, &'a V);
+    type Iter = Box<dyn Iterator<Item = (&'a K, &'a V)> + 'a>;
+    fn iter(&'a self) -> Self::Iter {
+        self.dyn_pair_iter()
+    }
+}
+
+impl<'a, K: ?Sized + 'a, V: ?Sized + 'a> IterView<'a> for dyn DynPairIterView<K, V> + Send + '_ {
+    type Item = (&'a K, &'a V);
+    type Iter = Box<dyn Iterator<Item = (&'a K, &'a V)> + 'a>;
+    fn iter(&'a self) -> Self::Iter {
+        self.dyn_pair_iter()
+    }
+}
+
+impl<'a, K: ?Sized + 'a, V: ?Sized + 'a> IterView<'a>
+    for dyn DynPairIterView<K, V> + Send + Sync + '_
+{
+    type Item = (&'a K, &'a V);
+    type Iter = Box<dyn Iterator<Item = (&'a K, &'a V)> + 'a>;
+    fn iter(&'a self) -> Self::Iter {
+        self.dyn_pair_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::{BTreeSet, VecDeque};
+
+    fn sum<'a, V: IterView<'a, Item = &'a i32> + ?Sized>(v: &'a V) -> i32 {
+        v.iter().sum()
+    }
+
+    #[test]
+    fn heterogeneous() {
+        let a = vec![1, 2];
+        let b: VecDeque<_> = [3].into_iter().collect();
+        let c: BTreeSet<_> = [4, 5].into_iter().collect();
+        let views: Vec<&dyn DynIterView<&i32>> = vec![&a, &b, &c];
+        let totals: Vec<_> = views.iter().map(|v| sum(*v)).collect();
+        assert_eq!(totals, [3, 3, 9]);
+
+        let boxed: Vec<Box<dyn DynRefIterView<i32>>> =
+            vec![Box::new(vec![6]), Box::new(BTreeSet::from([7]))];
+        let totals: Vec<_> = boxed.iter().map(sum).collect();
+        assert_eq!(totals, [6, 7]);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn owned_registry() {
+        use std::collections::{BTreeMap, HashMap};
+
+        struct Registry {
+            values: Vec<Box<dyn DynRefIterView<i32>>>,
+            settings: Vec<Box<dyn DynPairIterView<String, i32> + Send + Sync>>,
+        }
+
+        let registry = Registry {
+            values: vec![Box::new(vec![1, 2]), Box::new(VecDeque::from([3]))],
+            settings: vec![
+                Box::new(HashMap::from([("a".to_owned(), 1)])),
+                Box::new(BTreeMap::from([("b".to_owned(), 2), ("c".to_owned(), 3)])),
+            ],
+        };
+        let totals: Vec<_> = registry.values.iter().map(sum).collect();
+        assert_eq!(totals, [3, 3]);
+        let keys: Vec<Vec<_>> = registry
+            .settings
+            .iter()
+            .map(|v| v.iter().map(|(k, _)| k.as_str()).collect())
+            .collect();
+        assert_eq!(keys, [vec!["a"], vec!["b", "c"]]);
+        let values: i32 = registry
+            .settings
+            .iter()
+            .flat_map(|v| v.iter())
+            .map(|(_, v)| v)
+            .sum();
+        assert_eq!(values, 6);
+    }
+
+    #[test]
+    fn send_sync() {
+        let v = vec![1, 2, 3];
+        let view: &(dyn DynIterView<&i32> + Send + Sync) = &v;
+        assert_eq!(sum(view), 6);
+    }
+}
@@ -0,0 +1,59 @@
+//! `IterView` impls for [arrayvec](https://docs.rs/arrayvec), enabled by the `arrayvec` feature.
+
+use core::slice;
+
+use arrayvec::ArrayVec;
+
+use crate::{GatIterView, IterView, IterViewMut};
+
+impl<'a, T: 'a, const CAP: usize> IterView<'a> for ArrayVec<T, CAP> {
+    type Item = &'a T;
+    type Iter = slice::Iter<'a, T>;
+    fn iter(&'a self) -> Self::Iter {
+        self[..].iter()
+    }
+}
+
+impl<'a, T: 'a, const CAP: usize> IterViewMut<'a> for ArrayVec<T, CAP> {
+    type Item = &'a mut T;
+    type Iter = slice::IterMut<'a, T>;
+    fn iter_mut(&'a mut self) -> Self::Iter {
+        self[..].iter_mut()
+    }
+}
+
+impl<T, const CAP: usize> GatIterView for ArrayVec<T, CAP> {
+    type Item<'a>
+        = &'a T
+    where
+        Self: 'a;
+    type Iter<'a>
+        = slice::Iter<'a, T>
+    where
+        Self: 'a;
+    fn gat_iter(&self) -> Self::Iter<'_> {
+        self[..].iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ext::iter_view;
+    use crate::DoubleEndedIterView;
+
+    #[test]
+    fn iter_array_vec() {
+        let mut v: ArrayVec<u8, 4> = [1, 2, 3, 4].into();
+        v.pop();
+        let mut iter = iter_view(&v);
+        assert_eq!(iter.next(), Some(&1));
+        assert_eq!(iter.next(), Some(&2));
+        assert_eq!(iter.next(), Some(&3));
+        assert_eq!(iter.next(), None);
+
+        IterViewMut::iter_mut(&mut v).for_each(|v| *v += 1);
+        assert_eq!(v.iter_rev().next(), Some(&4));
+        assert_eq!(v.gat_iter().next(), Some(&2));
+    }
+}
@@ -0,0 +1,83 @@
+//! `IterView` impls for [bytes](https://docs.rs/bytes), enabled by the `bytes` feature.
+
+use core::slice;
+
+use bytes::{Bytes, BytesMut};
+
+use crate::{GatIterView, IterView, IterViewMut};
+
+impl<'a> IterView<'a> for Bytes {
+    type Item = &'a u8;
+    type Iter = slice::Iter<'a, u8>;
+    fn iter(&'a self) -> Self::Iter {
+        self[..].iter()
+    }
+}
+
+impl<'a> IterView<'a> for BytesMut {
+    type Item = &'a u8;
+    type Iter = slice::Iter<'a, u8>;
+    fn iter(&'a self) -> Self::Iter {
+        self[..].iter()
+    }
+}
+
+impl<'a> IterViewMut<'a> for BytesMut {
+    type Item = &'a mut u8;
+    type Iter = slice::IterMut<'a, u8>;
+    fn iter_mut(&'a mut self) -> Self::Iter {
+        self[..].iter_mut()
+    }
+}
+
+impl GatIterView for Bytes {
+    type Item<'a>
+        = &'a u8
+    where
+        Self: 'a;
+    type Iter<'a>
+        = slice::Iter<'a, u8>
+    where
+        Self: 'a;
+    fn gat_iter(&self) -> Self::Iter<'_> {
+        self[..].iter()
+    }
+}
+
+impl GatIterView for BytesMut {
+    type Item<'a>
+        = &'a u8
+    where
+        Self: 'a;
+    type Iter<'a>
+        = slice::Iter<'a, u8>
+    where
+        Self: 'a;
+    fn gat_iter(&self) -> Self::Iter<'_> {
+        self[..].iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ext::iter_view;
+
+    #[test]
+    fn iter_bytes() {
+        let v = Bytes::from_static(&[1, 2, 3]);
+        let mut iter = iter_view(&v);
+        assert_eq!(iter.next(), Some(&1));
+        assert_eq!(iter.next(), Some(&2));
+        assert_eq!(iter.next(), Some(&3));
+        assert_eq!(iter.next(), None);
+        assert_eq!(v.gat_iter().len(), 3);
+    }
+
+    #[test]
+    fn iter_bytes_mut() {
+        let mut v = BytesMut::from(&[1u8, 2][..]);
+        IterViewMut::iter_mut(&mut v).for_each(|v| *v += 1);
+        assert_eq!(iter_view(&v).collect::<Vec<_>>(), [&2, &3]);
+    }
+}
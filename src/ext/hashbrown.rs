@@ -0,0 +1,83 @@
+//! `IterView` impls for [hashbrown](https://docs.rs/hashbrown), enabled by the `hashbrown` feature.
+
+use hashbrown::{hash_map, hash_set, HashMap, HashSet};
+
+use crate::{GatIterView, IterView, IterViewMut};
+
+impl<'a, K: 'a, V: 'a, S: 'a> IterView<'a> for HashMap<K, V, S> {
+    type Item = (&'a K, &'a V);
+    type Iter = hash_map::Iter<'a, K, V>;
+    fn iter(&'a self) -> Self::Iter {
+        self.iter()
+    }
+}
+
+impl<'a, K: 'a, V: 'a, S: 'a> IterViewMut<'a> for HashMap<K, V, S> {
+    type Item = (&'a K, &'a mut V);
+    type Iter = hash_map::IterMut<'a, K, V>;
+    fn iter_mut(&'a mut self) -> Self::Iter {
+        self.iter_mut()
+    }
+}
+
+impl<'a, T: 'a, S: 'a> IterView<'a> for HashSet<T, S> {
+    type Item = &'a T;
+    type Iter = hash_set::Iter<'a, T>;
+    fn iter(&'a self) -> Self::Iter {
+        self.iter()
+    }
+}
+
+impl<K, V, S> GatIterView for HashMap<K, V, S> {
+    type Item<'a>
+        = (&'a K, &'a V)
+    where
+        Self: 'a;
+    type Iter<'a>
+        = hash_map::Iter<'a, K, V>
+    where
+        Self: 'a;
+    fn gat_iter(&self) -> Self::Iter<'_> {
+        self.iter()
+    }
+}
+
+impl<T, S> GatIterView for HashSet<T, S> {
+    type Item<'a>
+        = &'a T
+    where
+        Self: 'a;
+    type Iter<'a>
+        = hash_set::Iter<'a, T>
+    where
+        Self: 'a;
+    fn gat_iter(&self) -> Self::Iter<'_> {
+        self.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::hash::RandomState;
+
+    use super::*;
+    use crate::ext::iter_view;
+
+    #[test]
+    fn iter_hash_map() {
+        let mut m: HashMap<_, _, RandomState> = [("a", 1)].into_iter().collect();
+        IterViewMut::iter_mut(&mut m).for_each(|(_, v)| *v += 1);
+        let mut iter = iter_view(&m);
+        assert_eq!(iter.next(), Some((&"a", &2)));
+        assert_eq!(iter.next(), None);
+        assert_eq!(m.gat_iter().count(), 1);
+    }
+
+    #[test]
+    fn iter_hash_set() {
+        let s: HashSet<_, RandomState> = [1].into_iter().collect();
+        let mut iter = iter_view(&s);
+        assert_eq!(iter.next(), Some(&1));
+        assert_eq!(iter.next(), None);
+    }
+}
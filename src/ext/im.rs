@@ -0,0 +1,183 @@
+//! `IterView` impls for [im](https://docs.rs/im), enabled by the `im` feature.
+
+use core::hash::{BuildHasher, Hash};
+
+use im::{hashmap, hashset, ordmap, ordset, vector, HashMap, HashSet, OrdMap, OrdSet, Vector};
+
+use crate::{GatIterView, IterView, IterViewMut};
+
+impl<'a, A> IterView<'a> for Vector<A>
+where
+    A: Clone + 'a,
+{
+    type Item = &'a A;
+    type Iter = vector::Iter<'a, A>;
+    fn iter(&'a self) -> Self::Iter {
+        self.iter()
+    }
+}
+
+impl<'a, A> IterViewMut<'a> for Vector<A>
+where
+    A: Clone + 'a,
+{
+    type Item = &'a mut A;
+    type Iter = vector::IterMut<'a, A>;
+    fn iter_mut(&'a mut self) -> Self::Iter {
+        self.iter_mut()
+    }
+}
+
+impl<'a, K: 'a, V: 'a, S: 'a> IterView<'a> for HashMap<K, V, S> {
+    type Item = (&'a K, &'a V);
+    type Iter = hashmap::Iter<'a, K, V>;
+    fn iter(&'a self) -> Self::Iter {
+        self.iter()
+    }
+}
+
+impl<'a, K, V, S> IterViewMut<'a> for HashMap<K, V, S>
+where
+    K: Hash + Eq + Clone + 'a,
+    V: Clone + 'a,
+    S: BuildHasher + 'a,
+{
+    type Item = (&'a K, &'a mut V);
+    type Iter = hashmap::IterMut<'a, K, V>;
+    fn iter_mut(&'a mut self) -> Self::Iter {
+        self.iter_mut()
+    }
+}
+
+impl<'a, A: 'a, S: 'a> IterView<'a> for HashSet<A, S> {
+    type Item = &'a A;
+    type Iter = hashset::Iter<'a, A>;
+    fn iter(&'a self) -> Self::Iter {
+        self.iter()
+    }
+}
+
+impl<'a, K, V> IterView<'a> for OrdMap<K, V>
+where
+    K: Ord + 'a,
+    V: 'a,
+{
+    type Item = (&'a K, &'a V);
+    type Iter = ordmap::Iter<'a, K, V>;
+    fn iter(&'a self) -> Self::Iter {
+        self.iter()
+    }
+}
+
+impl<'a, A> IterView<'a> for OrdSet<A>
+where
+    A: Ord + 'a,
+{
+    type Item = &'a A;
+    type Iter = ordset::Iter<'a, A>;
+    fn iter(&'a self) -> Self::Iter {
+        self.iter()
+    }
+}
+
+impl<A: Clone> GatIterView for Vector<A> {
+    type Item<'a>
+        = &'a A
+    where
+        Self: 'a;
+    type Iter<'a>
+        = vector::Iter<'a, A>
+    where
+        Self: 'a;
+    fn gat_iter(&self) -> Self::Iter<'_> {
+        self.iter()
+    }
+}
+
+impl<K, V, S> GatIterView for HashMap<K, V, S> {
+    type Item<'a>
+        = (&'a K, &'a V)
+    where
+        Self: 'a;
+    type Iter<'a>
+        = hashmap::Iter<'a, K, V>
+    where
+        Self: 'a;
+    fn gat_iter(&self) -> Self::Iter<'_> {
+        self.iter()
+    }
+}
+
+impl<A, S> GatIterView for HashSet<A, S> {
+    type Item<'a>
+        = &'a A
+    where
+        Self: 'a;
+    type Iter<'a>
+        = hashset::Iter<'a, A>
+    where
+        Self: 'a;
+    fn gat_iter(&self) -> Self::Iter<'_> {
+        self.iter()
+    }
+}
+
+impl<K: Ord, V> GatIterView for OrdMap<K, V> {
+    type Item<'a>
+        = (&'a K, &'a V)
+    where
+        Self: 'a;
+    type Iter<'a>
+        = ordmap::Iter<'a, K, V>
+    where
+        Self: 'a;
+    fn gat_iter(&self) -> Self::Iter<'_> {
+        self.iter()
+    }
+}
+
+impl<A: Ord> GatIterView for OrdSet<A> {
+    type Item<'a>
+        = &'a A
+    where
+        Self: 'a;
+    type Iter<'a>
+        = ordset::Iter<'a, A>
+    where
+        Self: 'a;
+    fn gat_iter(&self) -> Self::Iter<'_> {
+        self.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ext::iter_view;
+
+    #[test]
+    fn iter_vector() {
+        let mut v: Vector<u8> = [1, 2, 3].into_iter().collect();
+        let mut iter = iter_view(&v);
+        assert_eq!(iter.next(), Some(&1));
+        assert_eq!(iter.next(), Some(&2));
+        assert_eq!(iter.next(), Some(&3));
+        assert_eq!(iter.next(), None);
+
+        IterViewMut::iter_mut(&mut v).for_each(|v| *v += 1);
+        assert_eq!(v.gat_iter().next(), Some(&2));
+    }
+
+    #[test]
+    fn iter_maps_and_sets() {
+        let mut m: HashMap<_, _> = [("a", 1)].into_iter().collect();
+        IterViewMut::iter_mut(&mut m).for_each(|(_, v)| *v += 1);
+        assert_eq!(iter_view(&m).collect::<Vec<_>>(), [(&"a", &2)]);
+        let s: HashSet<i32> = [1].into_iter().collect();
+        assert_eq!(iter_view(&s).collect::<Vec<_>>(), [&1]);
+        let m: OrdMap<_, _> = [(2, "b"), (1, "a")].into_iter().collect();
+        assert_eq!(iter_view(&m).collect::<Vec<_>>(), [(&1, &"a"), (&2, &"b")]);
+        let s: OrdSet<i32> = [2, 1].into_iter().collect();
+        assert_eq!(iter_view(&s).collect::<Vec<_>>(), [&1, &2]);
+    }
+}
@@ -0,0 +1,87 @@
+//! `IterView` impls for [indexmap](https://docs.rs/indexmap), enabled by the `indexmap` feature.
+
+use indexmap::{map, set, IndexMap, IndexSet};
+
+use crate::{GatIterView, IterView, IterViewMut};
+
+impl<'a, K: 'a, V: 'a, S: 'a> IterView<'a> for IndexMap<K, V, S> {
+    type Item = (&'a K, &'a V);
+    type Iter = map::Iter<'a, K, V>;
+    fn iter(&'a self) -> Self::Iter {
+        self.iter()
+    }
+}
+
+impl<'a, K: 'a, V: 'a, S: 'a> IterViewMut<'a> for IndexMap<K, V, S> {
+    type Item = (&'a K, &'a mut V);
+    type Iter = map::IterMut<'a, K, V>;
+    fn iter_mut(&'a mut self) -> Self::Iter {
+        self.iter_mut()
+    }
+}
+
+impl<'a, T: 'a, S: 'a> IterView<'a> for IndexSet<T, S> {
+    type Item = &'a T;
+    type Iter = set::Iter<'a, T>;
+    fn iter(&'a self) -> Self::Iter {
+        self.iter()
+    }
+}
+
+impl<K, V, S> GatIterView for IndexMap<K, V, S> {
+    type Item<'a>
+        = (&'a K, &'a V)
+    where
+        Self: 'a;
+    type Iter<'a>
+        = map::Iter<'a, K, V>
+    where
+        Self: 'a;
+    fn gat_iter(&self) -> Self::Iter<'_> {
+        self.iter()
+    }
+}
+
+impl<T, S> GatIterView for IndexSet<T, S> {
+    type Item<'a>
+        = &'a T
+    where
+        Self: 'a;
+    type Iter<'a>
+        = set::Iter<'a, T>
+    where
+        Self: 'a;
+    fn gat_iter(&self) -> Self::Iter<'_> {
+        self.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::hash::RandomState;
+
+    use super::*;
+    use crate::ext::iter_view;
+
+    #[test]
+    fn iter_index_map() {
+        let mut m: IndexMap<_, _, RandomState> = [("b", 2), ("a", 1)].into_iter().collect();
+        let mut iter = iter_view(&m);
+        assert_eq!(iter.next(), Some((&"b", &2)));
+        assert_eq!(iter.next(), Some((&"a", &1)));
+        assert_eq!(iter.next(), None);
+
+        IterViewMut::iter_mut(&mut m).for_each(|(_, v)| *v += 1);
+        assert_eq!(m.gat_iter().next(), Some((&"b", &3)));
+    }
+
+    #[test]
+    fn iter_index_set() {
+        let s: IndexSet<_, RandomState> = [3, 1, 2].into_iter().collect();
+        let mut iter = iter_view(&s);
+        assert_eq!(iter.next(), Some(&3));
+        assert_eq!(iter.next(), Some(&1));
+        assert_eq!(iter.next(), Some(&2));
+        assert_eq!(iter.next(), None);
+    }
+}
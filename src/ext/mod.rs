@@ -0,0 +1,23 @@
+//! `IterView` impls for collections of other crates, each enabled by the feature of the crate name.
+
+#[cfg(feature = "arrayvec")]
+mod arrayvec;
+#[cfg(feature = "bytes")]
+mod bytes;
+#[cfg(feature = "hashbrown")]
+mod hashbrown;
+#[cfg(feature = "im")]
+mod im;
+#[cfg(feature = "indexmap")]
+mod indexmap;
+#[cfg(feature = "slab")]
+mod slab;
+#[cfg(feature = "smallvec")]
+mod smallvec;
+
+/// Calls `IterView::iter()`, which the collections shadow with their own `iter()`.
+#[cfg(test)]
+#[allow(dead_code)]
+pub(crate) fn iter_view<'a, T: crate::IterView<'a> + ?Sized>(o: &'a T) -> T::Iter {
+    o.iter()
+}
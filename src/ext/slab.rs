@@ -0,0 +1,57 @@
+//! `IterView` impls for [slab](https://docs.rs/slab), enabled by the `slab` feature.
+
+use slab::Slab;
+
+use crate::{GatIterView, IterView, IterViewMut};
+
+impl<'a, T: 'a> IterView<'a> for Slab<T> {
+    type Item = (usize, &'a T);
+    type Iter = slab::Iter<'a, T>;
+    fn iter(&'a self) -> Self::Iter {
+        self.iter()
+    }
+}
+
+impl<'a, T: 'a> IterViewMut<'a> for Slab<T> {
+    type Item = (usize, &'a mut T);
+    type Iter = slab::IterMut<'a, T>;
+    fn iter_mut(&'a mut self) -> Self::Iter {
+        self.iter_mut()
+    }
+}
+
+impl<T> GatIterView for Slab<T> {
+    type Item<'a>
+        = (usize, &'a T)
+    where
+        Self: 'a;
+    type Iter<'a>
+        = slab::Iter<'a, T>
+    where
+        Self: 'a;
+    fn gat_iter(&self) -> Self::Iter<'_> {
+        self.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ext::iter_view;
+
+    #[test]
+    fn iter_slab() {
+        let mut s = Slab::new();
+        let a = s.insert(1);
+        let b = s.insert(2);
+        let c = s.insert(3);
+        s.remove(b);
+        let mut iter = iter_view(&s);
+        assert_eq!(iter.next(), Some((a, &1)));
+        assert_eq!(iter.next(), Some((c, &3)));
+        assert_eq!(iter.next(), None);
+
+        IterViewMut::iter_mut(&mut s).for_each(|(_, v)| *v += 1);
+        assert_eq!(s.gat_iter().next(), Some((a, &2)));
+    }
+}
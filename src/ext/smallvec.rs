@@ -0,0 +1,65 @@
+//! `IterView` impls for [smallvec](https://docs.rs/smallvec), enabled by the `smallvec` feature.
+
+use core::slice;
+
+use smallvec::{Array, SmallVec};
+
+use crate::{GatIterView, IterView, IterViewMut};
+
+impl<'a, A> IterView<'a> for SmallVec<A>
+where
+    A: Array,
+    A::Item: 'a,
+{
+    type Item = &'a A::Item;
+    type Iter = slice::Iter<'a, A::Item>;
+    fn iter(&'a self) -> Self::Iter {
+        self[..].iter()
+    }
+}
+
+impl<'a, A> IterViewMut<'a> for SmallVec<A>
+where
+    A: Array,
+    A::Item: 'a,
+{
+    type Item = &'a mut A::Item;
+    type Iter = slice::IterMut<'a, A::Item>;
+    fn iter_mut(&'a mut self) -> Self::Iter {
+        self[..].iter_mut()
+    }
+}
+
+impl<A: Array> GatIterView for SmallVec<A> {
+    type Item<'a>
+        = &'a A::Item
+    where
+        Self: 'a;
+    type Iter<'a>
+        = slice::Iter<'a, A::Item>
+    where
+        Self: 'a;
+    fn gat_iter(&self) -> Self::Iter<'_> {
+        self[..].iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ext::iter_view;
+
+    #[test]
+    fn iter_small_vec() {
+        let mut v: SmallVec<[u8; 2]> = SmallVec::from_slice(&[1, 2, 3]);
+        let mut iter = iter_view(&v);
+        assert_eq!(iter.next(), Some(&1));
+        assert_eq!(iter.next(), Some(&2));
+        assert_eq!(iter.next(), Some(&3));
+        assert_eq!(iter.next(), None);
+
+        IterViewMut::iter_mut(&mut v).for_each(|v| *v += 1);
+        assert_eq!(iter_view(&v).len(), 3);
+        assert_eq!(v.gat_iter().next(), Some(&2));
+    }
+}
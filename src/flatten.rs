@@ -0,0 +1,168 @@
+//! Views over nested collections, such as `Vec<Vec<T>>` and `HashMap<K, Vec<V>>`.
+
+use core::iter;
+
+use crate::IterView;
+
+/// View created by [`ViewExt::flatten_view()`](crate::ViewExt::flatten_view).
+#[derive(Clone, Copy, Debug)]
+pub struct FlattenView<V> {
+    view: V,
+}
+
+impl<V> FlattenView<V> {
+    pub(crate) fn new(view: V) -> Self {
+        Self { view }
+    }
+}
+
+impl<'a, V, U> IterView<'a> for FlattenView<V>
+where
+    V: IterView<'a, Item = &'a U>,
+    U: IterView<'a> + ?Sized + 'a,
+{
+    type Item = U::Item;
+    type Iter = iter::FlatMap<V::Iter, U::Iter, fn(&'a U) -> U::Iter>;
+    fn iter(&'a self) -> Self::Iter {
+        self.view.iter().flat_map(U::iter)
+    }
+}
+
+/// View created by [`ViewExt::flat_map_view()`](crate::ViewExt::flat_map_view).
+#[derive(Clone, Copy, Debug)]
+pub struct FlatMapView<V, F> {
+    view: V,
+    f: F,
+}
+
+impl<V, F> FlatMapView<V, F> {
+    pub(crate) fn new(view: V, f: F) -> Self {
+        Self { view, f }
+    }
+}
+
+impl<'a, V, F, I> IterView<'a> for FlatMapView<V, F>
+where
+    V: IterView<'a>,
+    F: Fn(V::Item) -> I + 'a,
+    I: IntoIterator<Item: 'a>,
+{
+    type Item = I::Item;
+    type Iter = iter::FlatMap<V::Iter, I, &'a F>;
+    fn iter(&'a self) -> Self::Iter {
+        self.view.iter().flat_map(&self.f)
+    }
+}
+
+/// View created by [`ViewExt::multi_map_view()`](crate::ViewExt::multi_map_view), yields a
+/// `(key, value)` pair for every value of every key.
+#[derive(Clone, Copy, Debug)]
+pub struct MultiMapView<V> {
+    view: V,
+}
+
+impl<V> MultiMapView<V> {
+    pub(crate) fn new(view: V) -> Self {
+        Self { view }
+    }
+}
+
+impl<'a, V, K, C> IterView<'a> for MultiMapView<V>
+where
+    V: IterView<'a, Item = (&'a K, &'a C)>,
+    K: ?Sized + 'a,
+    C: IterView<'a> + ?Sized + 'a,
+{
+    type Item = (&'a K, C::Item);
+    type Iter = MultiMapIter<'a, V::Iter, K, C>;
+    fn iter(&'a self) -> Self::Iter {
+        MultiMapIter {
+            outer: self.view.iter(),
+            inner: None,
+        }
+    }
+}
+
+/// Iterator of [`MultiMapView`].
+pub struct MultiMapIter<'a, I, K: ?Sized, C: IterView<'a> + ?Sized> {
+    outer: I,
+    inner: Option<(&'a K, C::Iter)>,
+}
+
+impl<'a, I, K, C> Clone for MultiMapIter<'a, I, K, C>
+where
+    I: Clone,
+    K: ?Sized,
+    C: IterView<'a, Iter: Clone> + ?Sized,
+{
+    fn clone(&self) -> Self {
+        Self {
+            outer: self.outer.clone(),
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<'a, I, K, C> Iterator for MultiMapIter<'a, I, K, C>
+where
+    I: Iterator<Item = (&'a K, &'a C)>,
+    K: ?Sized + 'a,
+    C: IterView<'a> + ?Sized + 'a,
+{
+    type Item = (&'a K, C::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some((k, values)) = &mut self.inner {
+                if let Some(v) = values.next() {
+                    return Some((*k, v));
+                }
+            }
+            let (k, values) = self.outer.next()?;
+            self.inner = Some((k, values.iter()));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ViewExt;
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn flatten() {
+        use std::collections::BTreeSet;
+
+        let v = vec![vec![1, 2], vec![], vec![3]];
+        let view = (&v).flatten_view();
+        for _ in 0..2 {
+            assert_eq!(view.iter().collect::<Vec<_>>(), [&1, &2, &3]);
+        }
+        assert_eq!(view.iter().next_back(), Some(&3));
+
+        let sets = [BTreeSet::from([1]), BTreeSet::from([2])];
+        assert_eq!((&sets).flatten_view().iter().sum::<i32>(), 3);
+    }
+
+    #[test]
+    fn flat_map() {
+        let v: [&[i32]; 2] = [&[1, 2], &[3]];
+        let view = (&v).flat_map_view(|v| v.iter().map(|v| v * 10));
+        assert_eq!(view.iter().collect::<Vec<_>>(), [10, 20, 30]);
+        assert_eq!(view.iter().count(), 3);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn multi_map() {
+        use std::collections::BTreeMap;
+
+        let m = BTreeMap::from([("a", vec![1, 2]), ("b", vec![]), ("c", vec![3])]);
+        let view = (&m).multi_map_view();
+        for _ in 0..2 {
+            let pairs: Vec<_> = view.iter().collect();
+            assert_eq!(pairs, [(&"a", &1), (&"a", &2), (&"c", &3)]);
+        }
+    }
+}
@@ -0,0 +1,443 @@
+//! Generic associated type flavor of [`IterView`].
+//!
+//! `IterView<'a>` carries the borrow lifetime on the trait, so a bound over "any borrow" has to be
+//! written as `for<'a> IterView<'a, Item = &'a T>`. `GatIterView` moves the lifetime onto the
+//! associated types instead, `T: GatIterView` is enough.
+//!
+//! Use [`AsIterView`] to pass a `GatIterView` where an `IterView` is expected, and [`AsGatIterView`]
+//! for the other direction.
+
+#[cfg(feature = "alloc")]
+use alloc::{borrow::ToOwned, boxed::Box, vec::Vec};
+use core::slice;
+
+use crate::{FromFnView, FuncIterView, IterView, OwnedFuncIterView};
+
+/// Like `IterView`, but the lifetime of the borrow lives on the associated types.
+pub trait GatIterView {
+    type Item<'a>
+    where
+        Self: 'a;
+    type Iter<'a>: Iterator<Item = Self::Item<'a>>
+    where
+        Self: 'a;
+    fn gat_iter(&self) -> Self::Iter<'_>;
+}
+
+impl<T: GatIterView + ?Sized> GatIterView for &T {
+    type Item<'a>
+        = T::Item<'a>
+    where
+        Self: 'a;
+    type Iter<'a>
+        = T::Iter<'a>
+    where
+        Self: 'a;
+    fn gat_iter(&self) -> Self::Iter<'_> {
+        (**self).gat_iter()
+    }
+}
+
+impl<T, const N: usize> GatIterView for [T; N] {
+    type Item<'a>
+        = &'a T
+    where
+        Self: 'a;
+    type Iter<'a>
+        = slice::Iter<'a, T>
+    where
+        Self: 'a;
+    fn gat_iter(&self) -> Self::Iter<'_> {
+        self[..].iter()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> GatIterView for Vec<T> {
+    type Item<'a>
+        = &'a T
+    where
+        Self: 'a;
+    type Iter<'a>
+        = slice::Iter<'a, T>
+    where
+        Self: 'a;
+    fn gat_iter(&self) -> Self::Iter<'_> {
+        self[..].iter()
+    }
+}
+
+impl<T> GatIterView for [T] {
+    type Item<'a>
+        = &'a T
+    where
+        Self: 'a;
+    type Iter<'a>
+        = slice::Iter<'a, T>
+    where
+        Self: 'a;
+    fn gat_iter(&self) -> Self::Iter<'_> {
+        self.iter()
+    }
+}
+
+impl<T> GatIterView for Option<T> {
+    type Item<'a>
+        = &'a T
+    where
+        Self: 'a;
+    type Iter<'a>
+        = core::option::Iter<'a, T>
+    where
+        Self: 'a;
+    fn gat_iter(&self) -> Self::Iter<'_> {
+        self.iter()
+    }
+}
+
+impl<T, E> GatIterView for Result<T, E> {
+    type Item<'a>
+        = &'a T
+    where
+        Self: 'a;
+    type Iter<'a>
+        = core::result::Iter<'a, T>
+    where
+        Self: 'a;
+    fn gat_iter(&self) -> Self::Iter<'_> {
+        self.iter()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: GatIterView + ?Sized> GatIterView for Box<T> {
+    type Item<'a>
+        = T::Item<'a>
+    where
+        Self: 'a;
+    type Iter<'a>
+        = T::Iter<'a>
+    where
+        Self: 'a;
+    fn gat_iter(&self) -> Self::Iter<'_> {
+        (**self).gat_iter()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: GatIterView + ?Sized> GatIterView for alloc::rc::Rc<T> {
+    type Item<'a>
+        = T::Item<'a>
+    where
+        Self: 'a;
+    type Iter<'a>
+        = T::Iter<'a>
+    where
+        Self: 'a;
+    fn gat_iter(&self) -> Self::Iter<'_> {
+        (**self).gat_iter()
+    }
+}
+
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+impl<T: GatIterView + ?Sized> GatIterView for alloc::sync::Arc<T> {
+    type Item<'a>
+        = T::Item<'a>
+    where
+        Self: 'a;
+    type Iter<'a>
+        = T::Iter<'a>
+    where
+        Self: 'a;
+    fn gat_iter(&self) -> Self::Iter<'_> {
+        (**self).gat_iter()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: GatIterView + ToOwned + ?Sized> GatIterView for alloc::borrow::Cow<'_, T> {
+    type Item<'a>
+        = T::Item<'a>
+    where
+        Self: 'a;
+    type Iter<'a>
+        = T::Iter<'a>
+    where
+        Self: 'a;
+    fn gat_iter(&self) -> Self::Iter<'_> {
+        (**self).gat_iter()
+    }
+}
+
+impl<P> GatIterView for core::pin::Pin<P>
+where
+    P: core::ops::Deref<Target: GatIterView>,
+{
+    type Item<'a>
+        = <P::Target as GatIterView>::Item<'a>
+    where
+        Self: 'a;
+    type Iter<'a>
+        = <P::Target as GatIterView>::Iter<'a>
+    where
+        Self: 'a;
+    fn gat_iter(&self) -> Self::Iter<'_> {
+        self.as_ref().get_ref().gat_iter()
+    }
+}
+
+impl<T: GatIterView + ?Sized> GatIterView for core::mem::ManuallyDrop<T> {
+    type Item<'a>
+        = T::Item<'a>
+    where
+        Self: 'a;
+    type Iter<'a>
+        = T::Iter<'a>
+    where
+        Self: 'a;
+    fn gat_iter(&self) -> Self::Iter<'_> {
+        (**self).gat_iter()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K, V, S> GatIterView for std::collections::HashMap<K, V, S> {
+    type Item<'a>
+        = (&'a K, &'a V)
+    where
+        Self: 'a;
+    type Iter<'a>
+        = std::collections::hash_map::Iter<'a, K, V>
+    where
+        Self: 'a;
+    fn gat_iter(&self) -> Self::Iter<'_> {
+        self.iter()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> GatIterView for alloc::collections::LinkedList<T> {
+    type Item<'a>
+        = &'a T
+    where
+        Self: 'a;
+    type Iter<'a>
+        = alloc::collections::linked_list::Iter<'a, T>
+    where
+        Self: 'a;
+    fn gat_iter(&self) -> Self::Iter<'_> {
+        self.iter()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> GatIterView for alloc::collections::BinaryHeap<T> {
+    type Item<'a>
+        = &'a T
+    where
+        Self: 'a;
+    type Iter<'a>
+        = alloc::collections::binary_heap::Iter<'a, T>
+    where
+        Self: 'a;
+    fn gat_iter(&self) -> Self::Iter<'_> {
+        self.iter()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> GatIterView for alloc::collections::VecDeque<T> {
+    type Item<'a>
+        = &'a T
+    where
+        Self: 'a;
+    type Iter<'a>
+        = alloc::collections::vec_deque::Iter<'a, T>
+    where
+        Self: 'a;
+    fn gat_iter(&self) -> Self::Iter<'_> {
+        self.iter()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T, S> GatIterView for std::collections::HashSet<T, S> {
+    type Item<'a>
+        = &'a T
+    where
+        Self: 'a;
+    type Iter<'a>
+        = std::collections::hash_set::Iter<'a, T>
+    where
+        Self: 'a;
+    fn gat_iter(&self) -> Self::Iter<'_> {
+        self.iter()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<K, V> GatIterView for alloc::collections::BTreeMap<K, V> {
+    type Item<'a>
+        = (&'a K, &'a V)
+    where
+        Self: 'a;
+    type Iter<'a>
+        = alloc::collections::btree_map::Iter<'a, K, V>
+    where
+        Self: 'a;
+    fn gat_iter(&self) -> Self::Iter<'_> {
+        self.iter()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> GatIterView for alloc::collections::BTreeSet<T> {
+    type Item<'a>
+        = &'a T
+    where
+        Self: 'a;
+    type Iter<'a>
+        = alloc::collections::btree_set::Iter<'a, T>
+    where
+        Self: 'a;
+    fn gat_iter(&self) -> Self::Iter<'_> {
+        self.iter()
+    }
+}
+
+impl<T, O, F, I> GatIterView for FuncIterView<'_, T, O, F, I>
+where
+    F: Fn(&O) -> I,
+    I: Iterator<Item = T>,
+{
+    type Item<'a>
+        = T
+    where
+        Self: 'a;
+    type Iter<'a>
+        = I
+    where
+        Self: 'a;
+    fn gat_iter(&self) -> Self::Iter<'_> {
+        (self.f)(self.o)
+    }
+}
+
+/// Only for closures whose iterator doesn't borrow the value, `IterView` impl has no such limit.
+impl<O, F, I> GatIterView for OwnedFuncIterView<O, F>
+where
+    F: Fn(&O) -> I,
+    I: Iterator,
+{
+    type Item<'a>
+        = I::Item
+    where
+        Self: 'a;
+    type Iter<'a>
+        = I
+    where
+        Self: 'a;
+    fn gat_iter(&self) -> Self::Iter<'_> {
+        (self.f)(&self.o)
+    }
+}
+
+impl<F, I> GatIterView for FromFnView<F>
+where
+    F: Fn() -> I,
+    I: Iterator,
+{
+    type Item<'a>
+        = I::Item
+    where
+        Self: 'a;
+    type Iter<'a>
+        = I
+    where
+        Self: 'a;
+    fn gat_iter(&self) -> Self::Iter<'_> {
+        (self.f)()
+    }
+}
+
+/// Wraps a `GatIterView` so it can be used as an `IterView`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct AsIterView<V>(pub V);
+
+impl<'a, V: GatIterView + 'a> IterView<'a> for AsIterView<V> {
+    type Item = V::Item<'a>;
+    type Iter = V::Iter<'a>;
+    fn iter(&'a self) -> Self::Iter {
+        self.0.gat_iter()
+    }
+}
+
+/// Wraps an `IterView` so it can be used as a `GatIterView`.
+///
+/// The wrapped view must be an `IterView` for every lifetime, which holds for owned collections of
+/// `'static` items, such as `Vec<String>`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct AsGatIterView<V>(pub V);
+
+impl<V> GatIterView for AsGatIterView<V>
+where
+    V: for<'a> IterView<'a>,
+{
+    type Item<'a>
+        = <V as IterView<'a>>::Item
+    where
+        Self: 'a;
+    type Iter<'a>
+        = <V as IterView<'a>>::Iter
+    where
+        Self: 'a;
+    fn gat_iter(&self) -> Self::Iter<'_> {
+        self.0.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn gat_iter_view<T: GatIterView + ?Sized>(o: &T) -> T::Iter<'_> {
+        o.gat_iter()
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn gat_vec() {
+        let v = vec![1, 2, 3];
+        let mut iter = gat_iter_view(&v);
+        assert_eq!(iter.next(), Some(&1));
+        assert_eq!(iter.next(), Some(&2));
+        assert_eq!(iter.next(), Some(&3));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn gat_func() {
+        let v = 3usize;
+        let f = crate::iter(&v, |v: &usize| 0..*v);
+        assert_eq!(gat_iter_view(&f).collect::<Vec<_>>(), [0, 1, 2]);
+
+        let f = crate::iter_owned(2usize, |v: &usize| 0..*v);
+        assert_eq!(gat_iter_view(&f).count(), 2);
+        let f = crate::from_fn(|| 0..4);
+        assert_eq!(gat_iter_view(&f).sum::<i32>(), 6);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn bridge() {
+        let v: std::collections::LinkedList<_> = [1, 2].into_iter().collect();
+        let view = AsIterView(&v);
+        let mut iter = IterView::iter(&view);
+        assert_eq!(iter.next(), Some(&1));
+        assert_eq!(iter.next(), Some(&2));
+        assert_eq!(iter.next(), None);
+
+        let view = AsGatIterView(vec![1, 2]);
+        assert_eq!(gat_iter_view(&view).collect::<Vec<_>>(), [&1, &2]);
+    }
+}
@@ -0,0 +1,268 @@
+//! Random access views, see [`IndexedView`].
+
+#[cfg(feature = "alloc")]
+use alloc::{collections::VecDeque, vec::Vec};
+use core::cmp::Ordering;
+use core::iter::FusedIterator;
+use core::ops::{Bound, RangeBounds};
+
+use crate::{ExactSizeIterView, IterView};
+
+/// `IterView` with O(1) access to the item at an index.
+///
+/// `view_len()` comes from [`ExactSizeIterView`], `get(i)` must return the same item as the `i`-th item
+/// of `iter()`.
+///
+/// `Vec`, slices and `VecDeque` have inherent methods of the same names, call them through the trait
+/// on concrete types, such as `IndexedView::first(&v)`.
+pub trait IndexedView<'a>: ExactSizeIterView<'a> {
+    /// Returns the item at `index`, or `None` if out of bounds.
+    fn get(&'a self, index: usize) -> Option<Self::Item>;
+
+    fn first(&'a self) -> Option<Self::Item> {
+        self.get(0)
+    }
+
+    fn last(&'a self) -> Option<Self::Item> {
+        self.view_len().checked_sub(1).and_then(|i| self.get(i))
+    }
+
+    /// Returns a view of the items in `range`.
+    ///
+    /// Panics if the range is out of bounds or its start is greater than its end, same as slicing.
+    fn slice_view<R: RangeBounds<usize>>(&'a self, range: R) -> SliceView<'a, Self> {
+        let len = self.view_len();
+        let start = match range.start_bound() {
+            Bound::Included(&i) => i,
+            Bound::Excluded(&i) => i.checked_add(1).expect("slice_view() start overflow"),
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&i) => i.checked_add(1).expect("slice_view() end overflow"),
+            Bound::Excluded(&i) => i,
+            Bound::Unbounded => len,
+        };
+        assert!(start <= end, "slice_view() start {start} > end {end}");
+        assert!(
+            end <= len,
+            "slice_view() end {end} out of range for length {len}"
+        );
+        SliceView {
+            view: self,
+            start,
+            end,
+        }
+    }
+
+    /// Binary searches a view sorted by `f`, same as `slice::binary_search_by()`.
+    fn binary_search_by<F>(&'a self, mut f: F) -> Result<usize, usize>
+    where
+        F: FnMut(Self::Item) -> Ordering,
+    {
+        let (mut lo, mut hi) = (0, self.view_len());
+        while lo < hi {
+            let mid = lo + (hi - lo) / 2;
+            match f(self.get(mid).expect("index within view_len()")) {
+                Ordering::Less => lo = mid + 1,
+                Ordering::Greater => hi = mid,
+                Ordering::Equal => return Ok(mid),
+            }
+        }
+        Err(lo)
+    }
+
+    /// Binary searches a view sorted by the key `f`, same as `slice::binary_search_by_key()`.
+    fn binary_search_by_key<B, F>(&'a self, b: &B, mut f: F) -> Result<usize, usize>
+    where
+        B: Ord,
+        F: FnMut(Self::Item) -> B,
+    {
+        self.binary_search_by(|v| f(v).cmp(b))
+    }
+
+    /// Returns the index of the first item not matching `pred`, the view must be partitioned by
+    /// `pred`, same as `slice::partition_point()`.
+    fn partition_point<P>(&'a self, mut pred: P) -> usize
+    where
+        P: FnMut(Self::Item) -> bool,
+    {
+        self.binary_search_by(|v| {
+            if pred(v) {
+                Ordering::Less
+            } else {
+                Ordering::Greater
+            }
+        })
+        .unwrap_or_else(|i| i)
+    }
+}
+
+impl<'a, T: IndexedView<'a> + ?Sized> IndexedView<'a> for &'a T {
+    fn get(&'a self, index: usize) -> Option<Self::Item> {
+        (**self).get(index)
+    }
+}
+
+impl<'a, T: 'a> IndexedView<'a> for [T] {
+    fn get(&'a self, index: usize) -> Option<&'a T> {
+        <[T]>::get(self, index)
+    }
+}
+
+impl<'a, T: 'a, const N: usize> IndexedView<'a> for [T; N] {
+    fn get(&'a self, index: usize) -> Option<&'a T> {
+        <[T]>::get(self, index)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a, T: 'a> IndexedView<'a> for Vec<T> {
+    fn get(&'a self, index: usize) -> Option<&'a T> {
+        <[T]>::get(self, index)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a, T: 'a> IndexedView<'a> for VecDeque<T> {
+    fn get(&'a self, index: usize) -> Option<&'a T> {
+        VecDeque::get(self, index)
+    }
+}
+
+/// View of a sub range of an `IndexedView`, created by [`IndexedView::slice_view()`].
+#[derive(Debug)]
+pub struct SliceView<'a, V: ?Sized> {
+    view: &'a V,
+    start: usize,
+    end: usize,
+}
+
+impl<V: ?Sized> Clone for SliceView<'_, V> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<V: ?Sized> Copy for SliceView<'_, V> {}
+
+impl<'s, 'a: 's, V: IndexedView<'a> + ?Sized> IterView<'s> for SliceView<'a, V> {
+    type Item = V::Item;
+    type Iter = IndexedIter<'a, V>;
+    fn iter(&'s self) -> Self::Iter {
+        IndexedIter {
+            view: self.view,
+            front: self.start,
+            back: self.end,
+        }
+    }
+}
+
+impl<'s, 'a: 's, V: IndexedView<'a> + ?Sized> IndexedView<'s> for SliceView<'a, V> {
+    fn get(&'s self, index: usize) -> Option<Self::Item> {
+        if index < self.end - self.start {
+            self.view.get(self.start + index)
+        } else {
+            None
+        }
+    }
+}
+
+/// Iterator of an `IndexedView` by index, the iterator of [`SliceView`].
+#[derive(Debug)]
+pub struct IndexedIter<'a, V: ?Sized> {
+    view: &'a V,
+    front: usize,
+    back: usize,
+}
+
+impl<V: ?Sized> Clone for IndexedIter<'_, V> {
+    fn clone(&self) -> Self {
+        Self {
+            view: self.view,
+            front: self.front,
+            back: self.back,
+        }
+    }
+}
+
+impl<'a, V: IndexedView<'a> + ?Sized> Iterator for IndexedIter<'a, V> {
+    type Item = V::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
+        }
+        self.front += 1;
+        self.view.get(self.front - 1)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let n = self.back - self.front;
+        (n, Some(n))
+    }
+}
+
+impl<'a, V: IndexedView<'a> + ?Sized> DoubleEndedIterator for IndexedIter<'a, V> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
+        }
+        self.back -= 1;
+        self.view.get(self.back)
+    }
+}
+
+impl<'a, V: IndexedView<'a> + ?Sized> ExactSizeIterator for IndexedIter<'a, V> {}
+
+impl<'a, V: IndexedView<'a> + ?Sized> FusedIterator for IndexedIter<'a, V> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ViewExt;
+
+    fn median<'a, V: IndexedView<'a> + ?Sized>(v: &'a V) -> Option<V::Item> {
+        v.get(v.view_len() / 2)
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn indexed() {
+        let v = vec![1, 2, 3];
+        assert_eq!(median(&v), Some(&2));
+        assert_eq!(median(&[1u8; 0]), None);
+        let mut d: std::collections::VecDeque<_> = [2, 3].into_iter().collect();
+        d.push_front(1);
+        assert_eq!(IndexedView::first(&d), Some(&1));
+        assert_eq!(IndexedView::last(&d), Some(&3));
+        assert_eq!(IndexedView::get(&d, 3), None);
+    }
+
+    #[test]
+    fn slice_and_search() {
+        let v = [1, 3, 5, 7, 9];
+        let s = v.slice_view(1..=3);
+        assert_eq!(s.iter().collect::<Vec<_>>(), [&3, &5, &7]);
+        assert_eq!(s.iter().rev().collect::<Vec<_>>(), [&7, &5, &3]);
+        assert_eq!(s.slice_view(1..).iter().len(), 2);
+        assert_eq!(IndexedView::binary_search_by_key(&s, &7, |v| *v), Ok(2));
+        assert_eq!(IndexedView::binary_search_by_key(&s, &4, |v| *v), Err(1));
+        assert_eq!(IndexedView::partition_point(&v, |v| *v < 6), 3);
+    }
+
+    #[test]
+    fn adapters_stay_indexed() {
+        let v = [1, 2, 3];
+        let view = (&v).map_view(|v| v * 10).rev_view();
+        assert_eq!(median(&view), Some(20));
+        assert_eq!(IndexedView::first(&view), Some(30));
+        assert_eq!(view.slice_view(..2).iter().collect::<Vec<_>>(), [30, 20]);
+        assert_eq!(view.partition_point(|v| v > 15), 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "out of range")]
+    fn slice_out_of_range() {
+        [1, 2].slice_view(1..3);
+    }
+}
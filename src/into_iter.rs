@@ -0,0 +1,217 @@
+//! `IntoIterator` for references of views, so views work in `for` loops and with std functions
+//! taking `impl IntoIterator`.
+
+use crate::{GatIterView, IterView};
+
+/// Wraps a reference of any `IterView` as an `IntoIterator`.
+///
+/// ```rust
+/// use std::collections::HashSet;
+/// use std::hash::Hash;
+/// use iter_view::{AsIntoIter, IterView};
+///
+/// fn distinct<'a, V: IterView<'a, Item: Eq + Hash> + ?Sized>(v: &'a V) -> usize {
+///     HashSet::<V::Item>::from_iter(AsIntoIter(v)).len()
+/// }
+///
+/// assert_eq!(distinct(&[1, 2, 2]), 2);
+/// ```
+#[derive(Debug)]
+pub struct AsIntoIter<'a, V: ?Sized>(pub &'a V);
+
+impl<V: ?Sized> Clone for AsIntoIter<'_, V> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<V: ?Sized> Copy for AsIntoIter<'_, V> {}
+
+impl<'a, V: IterView<'a> + ?Sized> IntoIterator for AsIntoIter<'a, V> {
+    type Item = V::Item;
+    type IntoIter = V::Iter;
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+/// Bridges a collection whose reference implements `IntoIterator` to `IterView` and
+/// `GatIterView`, for collections of other crates that don't implement `IterView`.
+///
+/// Wrap an owned collection directly, or use [`view_of()`] to wrap a reference.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct RefIntoIterView<T: ?Sized>(pub T);
+
+/// Views a collection through its `IntoIterator for &T` impl, without moving or copying it.
+///
+/// ```rust
+/// use std::collections::LinkedList;
+/// use iter_view::{view_of, IterView};
+///
+/// fn total<'a, V: IterView<'a, Item = &'a i32> + ?Sized>(v: &'a V) -> i32 {
+///     v.iter().sum()
+/// }
+///
+/// let list: LinkedList<_> = [1, 2, 3].into_iter().collect();
+/// assert_eq!(total(view_of(&list)), 6);
+/// ```
+pub fn view_of<T: ?Sized>(collection: &T) -> &RefIntoIterView<T> {
+    // SAFETY: `RefIntoIterView` is `repr(transparent)` over `T`, so they share layout and the
+    // pointer metadata of unsized `T`.
+    unsafe { &*(collection as *const T as *const RefIntoIterView<T>) }
+}
+
+impl<'a, T> IterView<'a> for RefIntoIterView<T>
+where
+    T: ?Sized + 'a,
+    &'a T: IntoIterator<Item: 'a>,
+{
+    type Item = <&'a T as IntoIterator>::Item;
+    type Iter = <&'a T as IntoIterator>::IntoIter;
+    fn iter(&'a self) -> Self::Iter {
+        self.0.into_iter()
+    }
+}
+
+impl<T> GatIterView for RefIntoIterView<T>
+where
+    T: ?Sized,
+    for<'a> &'a T: IntoIterator,
+{
+    type Item<'a>
+        = <&'a T as IntoIterator>::Item
+    where
+        Self: 'a;
+    type Iter<'a>
+        = <&'a T as IntoIterator>::IntoIter
+    where
+        Self: 'a;
+    fn gat_iter(&self) -> Self::Iter<'_> {
+        self.0.into_iter()
+    }
+}
+
+macro_rules! into_iter_for_ref {
+    ($([$($gen:tt)*] $view:ty,)*) => {$(
+        impl<'r, $($gen)*> IntoIterator for &'r $view
+        where
+            $view: IterView<'r>,
+        {
+            type Item = <$view as IterView<'r>>::Item;
+            type IntoIter = <$view as IterView<'r>>::Iter;
+            fn into_iter(self) -> Self::IntoIter {
+                IterView::iter(self)
+            }
+        }
+    )*};
+}
+
+into_iter_for_ref! {
+    [V, F] crate::MapView<V, F>,
+    [V, P] crate::FilterView<V, P>,
+    [V, F] crate::FilterMapView<V, F>,
+    [V] crate::TakeView<V>,
+    [V] crate::SkipView<V>,
+    [V] crate::StepByView<V>,
+    [A, B] crate::ChainView<A, B>,
+    [V] crate::EnumerateView<V>,
+    [A, B] crate::ZipView<A, B>,
+    [V] crate::RevView<V>,
+    ['v, C: ?Sized] crate::ChunksView<'v, C>,
+    ['v, T] crate::DequeSlice<'v, T>,
+    [I] crate::CloneView<I>,
+    [V] crate::FlattenView<V>,
+    [V, F] crate::FlatMapView<V, F>,
+    [V] crate::MultiMapView<V>,
+    [V] crate::AsIterView<V>,
+    [T: ?Sized] RefIntoIterView<T>,
+    ['v, V: ?Sized] crate::SliceView<'v, V>,
+    ['v, T, O, F, I] crate::FuncIterView<'v, T, O, F, I>,
+    [O, F] crate::OwnedFuncIterView<O, F>,
+    [F] crate::FromFnView<F>,
+    [V] crate::Sorted<V>,
+    [A, B] crate::SetView<A, B>,
+    [A, B, F] crate::MergeJoinView<A, B, F>,
+}
+
+#[cfg(feature = "alloc")]
+into_iter_for_ref! {
+    [V] crate::RingWindowsView<V>,
+    [I: Iterator] crate::MemoView<I>,
+    [O] crate::MergeView<O>,
+    ['v, C: ?Sized, Q: ?Sized, R] crate::BTreeRangeView<'v, C, Q, R>,
+    ['v, N, F] crate::TreeView<'v, N, F>,
+    ['v, N, F] crate::TreeDepthView<'v, N, F>,
+    ['v, N, F] crate::TreePathView<'v, N, F>,
+}
+
+#[cfg(feature = "std")]
+into_iter_for_ref! {
+    [I: Iterator] crate::SyncMemoView<I>,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use super::*;
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn for_loop() {
+        use std::collections::HashSet;
+
+        use crate::{IntoView, ViewExt};
+
+        let v = vec![1, 2, 3, 4];
+        let view = (&v).filter_view(|v| *v % 2 == 0).map_view(|v| v * 10);
+        let mut items = vec![];
+        for _ in 0..2 {
+            for item in &view {
+                items.push(item);
+            }
+        }
+        assert_eq!(items, [20, 40, 20, 40]);
+
+        let mut set = HashSet::new();
+        set.extend(&(0..3).into_view());
+        assert_eq!(set, HashSet::from([0, 1, 2]));
+
+        let x = 3;
+        let view = crate::iter(&x, |x: &i32| 0..*x);
+        assert_eq!(Vec::from_iter(&view), [0, 1, 2]);
+
+        struct Node(Vec<Node>);
+        let root = Node(vec![Node(vec![]), Node(vec![Node(vec![])])]);
+        let tree = crate::pre_order(&root, |n: &Node| &n.0);
+        assert_eq!((&tree).into_iter().count(), 4);
+    }
+
+    #[test]
+    fn ref_into_iter() {
+        let set = BTreeSet::from([2, 1]);
+        let view = view_of(&set);
+        for _ in 0..2 {
+            assert_eq!(view.iter().collect::<Vec<_>>(), [&1, &2]);
+        }
+        assert_eq!(view.gat_iter().count(), 2);
+
+        let slice: &[i32] = &[1, 2, 3];
+        assert_eq!(view_of(slice).iter().sum::<i32>(), 6);
+
+        let owned = RefIntoIterView(vec!["a", "b"]);
+        assert_eq!(owned.iter().copied().collect::<String>(), "ab");
+        assert_eq!(owned, RefIntoIterView(vec!["a", "b"]));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn as_into_iter() {
+        let set = BTreeSet::from([3, 1, 2]);
+        let wrapped = AsIntoIter(&set);
+        assert_eq!(wrapped.into_iter().collect::<Vec<_>>(), [&1, &2, &3]);
+        assert_eq!(wrapped.into_iter().count(), 3);
+        assert!(AsIntoIter(&vec![1, 2]).into_iter().eq(AsIntoIter(&[1, 2])));
+    }
+}
@@ -95,9 +95,164 @@
 //! }
 //!
 //! ```
+//!
+//! ## Derive
+//!
+//! With the `derive` feature, `#[derive(IterView, IterViewMut)]` forwards the impl to the only field
+//! of a struct, or to the field marked with `#[iter_view]`. `#[iter_view(map = path::to::fn, item =
+//! Type)]` maps each item with the function. `item` is required, because the type of a function
+//! can't be named in the generated impl, so its result type can't be inferred on stable Rust. Item
+//! types borrowing from the struct use the lifetime `'view`, the struct can't declare a lifetime
+//! with that name.
+//!
+#![cfg_attr(
+    feature = "derive",
+    doc = r#"
+```rust
+use iter_view::IterView;
+
+struct Member {
+    name: String,
+}
+
+impl Member {
+    fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+#[derive(IterView)]
+struct Roster {
+    #[iter_view(map = Member::name, item = &'view str)]
+    members: Vec<Member>,
+    updated_at: u64,
+}
+
+let roster = Roster { members: vec![Member { name: "a".to_owned() }], updated_at: 0 };
+assert_eq!(roster.iter().collect::<Vec<_>>(), ["a"]);
+```
+
+`map` without `item` doesn't compile:
+
+```compile_fail
+# struct Member {
+#     name: String,
+# }
+#
+# impl Member {
+#     fn name(&self) -> &str {
+#         &self.name
+#     }
+# }
+#[derive(iter_view::IterView)]
+struct Roster {
+    #[iter_view(map = Member::name)]
+    members: Vec<Member>,
+}
+```
+"#
+)]
+//!
+//! ## Cargo features
+//!
+//! The crate is `no_std` when the default `std` feature is off.
+//!
+//! - `std` (default): impls for `HashMap` and `HashSet`, `SyncMemoView` and `multiset_eq()`, implies
+//!   `alloc`.
+//! - `alloc`: impls for `Vec`, `Box`, `Rc`, `Arc`, `Cow`, `LinkedList`, `BinaryHeap`, `VecDeque`, `BTreeMap`
+//!   and `BTreeSet`, `DynIterView`, `DynRefIterView`, `DynPairIterView`, `RangeView`, `MemoView`,
+//!   `merge()`, `ring_windows_view()`, and the tree views of `pre_order()`, `post_order()` and
+//!   `breadth_first()`.
+//! - `arrayvec`, `bytes`, `hashbrown`, `im`, `indexmap`, `slab`, `smallvec`: `IterView`, `IterViewMut`
+//!   and `GatIterView` impls for the collections of the crate with the same name.
+//! - `derive`: `#[derive(IterView, IterViewMut)]` to forward the impl to a field of a struct.
+//! - `rayon`: `ParIterView` for parallel iteration with [rayon](https://docs.rs/rayon).
+//! - `serde`: `as_seq()` and `as_map()` to serialize views without collecting.
+//! - `stream`: `StreamView` to create `futures_core::Stream` from immutable reference.
 
-use std::marker::PhantomData;
-use std::slice;
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "alloc")]
+use alloc::{borrow::ToOwned, boxed::Box, vec::Vec};
+use core::marker::PhantomData;
+use core::slice;
+
+mod adapters;
+mod chunks;
+mod clone;
+mod cmp;
+mod display;
+#[cfg(feature = "alloc")]
+mod dyn_view;
+mod ext;
+mod flatten;
+mod gat;
+mod indexed;
+mod into_iter;
+#[cfg(feature = "alloc")]
+mod memo;
+#[cfg(feature = "rayon")]
+mod par;
+#[cfg(feature = "alloc")]
+mod range;
+#[cfg(feature = "serde")]
+mod serialize;
+mod sorted;
+#[cfg(feature = "stream")]
+mod stream;
+#[cfg(feature = "alloc")]
+mod tree;
+
+pub use adapters::{
+    ChainView, EnumerateView, FilterMapView, FilterView, MapView, RevView, SkipView, StepByView,
+    TakeView, ViewExt, ZipView,
+};
+pub use chunks::{ChunksIter, ChunksView, ContiguousView, DequeSlice, DequeSliceIter};
+#[cfg(feature = "alloc")]
+pub use chunks::{RingWindowsIter, RingWindowsView};
+pub use clone::{CloneView, IntoView};
+#[cfg(feature = "std")]
+pub use cmp::multiset_eq;
+pub use cmp::{view_cmp, view_eq, view_hash, view_partial_cmp, ByContent};
+pub use display::{DisplayMapView, DisplayView, DisplayViewExt};
+#[cfg(feature = "alloc")]
+pub use dyn_view::{DynIterView, DynPairIterView, DynRefIterView};
+pub use flatten::{FlatMapView, FlattenView, MultiMapIter, MultiMapView};
+pub use gat::{AsGatIterView, AsIterView, GatIterView};
+pub use indexed::{IndexedIter, IndexedView, SliceView};
+pub use into_iter::{view_of, AsIntoIter, RefIntoIterView};
+#[cfg(feature = "alloc")]
+pub use memo::{MemoIter, MemoView};
+#[cfg(feature = "std")]
+pub use memo::{SyncMemoIter, SyncMemoView};
+#[cfg(feature = "rayon")]
+pub use par::{ParBridge, ParIterView};
+#[cfg(feature = "alloc")]
+pub use range::{BTreeRangeView, RangeView};
+#[cfg(feature = "serde")]
+pub use serialize::{as_map, as_seq, MapFormat, SeqFormat, SerializeView};
+pub use sorted::{
+    assume_sorted, difference, intersection, merge_join_by, symmetric_difference, try_sorted,
+    union, EitherOrBoth, MergeJoinIter, MergeJoinView, SetIter, SetView, Sorted, SortedView,
+};
+#[cfg(feature = "alloc")]
+pub use sorted::{merge, MergeIter, MergeView};
+#[cfg(feature = "stream")]
+pub use stream::{stream, FuncStreamView, IterStream, StreamView};
+#[cfg(feature = "alloc")]
+pub use tree::{
+    breadth_first, post_order, pre_order, TreeDepthIter, TreeDepthView, TreeIter, TreePathIter,
+    TreePathView, TreeView,
+};
+
+#[cfg(feature = "derive")]
+pub use iter_view_derive::{IterView, IterViewMut};
+
+#[cfg(all(test, feature = "derive"))]
+extern crate self as iter_view;
 
 /// Like IntoIterator, but not consume current object, return a readonly iterator.
 pub trait IterView<'a> {
@@ -122,6 +277,7 @@ impl<'a, T: 'a, const N: usize> IterView<'a> for [T; N] {
     }
 }
 
+#[cfg(feature = "alloc")]
 impl<'a, T: 'a> IterView<'a> for Vec<T> {
     type Item = &'a T;
     type Iter = slice::Iter<'a, T>;
@@ -140,7 +296,7 @@ impl<'a, T: 'a> IterView<'a> for [T] {
 
 impl<'a, T: 'a> IterView<'a> for Option<T> {
     type Item = &'a T;
-    type Iter = std::option::Iter<'a, T>;
+    type Iter = core::option::Iter<'a, T>;
     fn iter(&'a self) -> Self::Iter {
         self.iter()
     }
@@ -148,15 +304,40 @@ impl<'a, T: 'a> IterView<'a> for Option<T> {
 
 impl<'a, T: 'a, E: 'a> IterView<'a> for Result<T, E> {
     type Item = &'a T;
-    type Iter = std::result::Iter<'a, T>;
+    type Iter = core::result::Iter<'a, T>;
     fn iter(&'a self) -> Self::Iter {
         self.iter()
     }
 }
 
+#[cfg(feature = "alloc")]
 impl<'a, T> IterView<'a> for Box<T>
 where
-    T: IterView<'a>,
+    T: IterView<'a> + ?Sized,
+{
+    type Item = T::Item;
+    type Iter = T::Iter;
+    fn iter(&'a self) -> Self::Iter {
+        self.as_ref().iter()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a, T> IterView<'a> for alloc::rc::Rc<T>
+where
+    T: IterView<'a> + ?Sized,
+{
+    type Item = T::Item;
+    type Iter = T::Iter;
+    fn iter(&'a self) -> Self::Iter {
+        self.as_ref().iter()
+    }
+}
+
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+impl<'a, T> IterView<'a> for alloc::sync::Arc<T>
+where
+    T: IterView<'a> + ?Sized,
 {
     type Item = T::Item;
     type Iter = T::Iter;
@@ -165,10 +346,46 @@ where
     }
 }
 
-impl<'a, K, V> IterView<'a> for std::collections::HashMap<K, V>
+#[cfg(feature = "alloc")]
+impl<'a, B> IterView<'a> for alloc::borrow::Cow<'_, B>
 where
-    K: Eq + std::hash::Hash + 'a,
+    B: IterView<'a> + ToOwned + ?Sized,
+{
+    type Item = B::Item;
+    type Iter = B::Iter;
+    fn iter(&'a self) -> Self::Iter {
+        self.as_ref().iter()
+    }
+}
+
+impl<'a, P> IterView<'a> for core::pin::Pin<P>
+where
+    P: core::ops::Deref<Target: IterView<'a>>,
+{
+    type Item = <P::Target as IterView<'a>>::Item;
+    type Iter = <P::Target as IterView<'a>>::Iter;
+    fn iter(&'a self) -> Self::Iter {
+        self.as_ref().get_ref().iter()
+    }
+}
+
+impl<'a, T> IterView<'a> for core::mem::ManuallyDrop<T>
+where
+    T: IterView<'a> + ?Sized,
+{
+    type Item = T::Item;
+    type Iter = T::Iter;
+    fn iter(&'a self) -> Self::Iter {
+        (**self).iter()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'a, K, V, S> IterView<'a> for std::collections::HashMap<K, V, S>
+where
+    K: 'a,
     V: 'a,
+    S: 'a,
 {
     type Item = (&'a K, &'a V);
     type Iter = std::collections::hash_map::Iter<'a, K, V>;
@@ -177,42 +394,47 @@ where
     }
 }
 
-impl<'a, T> IterView<'a> for std::collections::LinkedList<T>
+#[cfg(feature = "alloc")]
+impl<'a, T> IterView<'a> for alloc::collections::LinkedList<T>
 where
     T: 'a,
 {
     type Item = &'a T;
-    type Iter = std::collections::linked_list::Iter<'a, T>;
+    type Iter = alloc::collections::linked_list::Iter<'a, T>;
     fn iter(&'a self) -> Self::Iter {
         self.iter()
     }
 }
 
-impl<'a, T> IterView<'a> for std::collections::BinaryHeap<T>
+#[cfg(feature = "alloc")]
+impl<'a, T> IterView<'a> for alloc::collections::BinaryHeap<T>
 where
     T: 'a,
 {
     type Item = &'a T;
-    type Iter = std::collections::binary_heap::Iter<'a, T>;
+    type Iter = alloc::collections::binary_heap::Iter<'a, T>;
     fn iter(&'a self) -> Self::Iter {
         self.iter()
     }
 }
 
-impl<'a, T> IterView<'a> for std::collections::VecDeque<T>
+#[cfg(feature = "alloc")]
+impl<'a, T> IterView<'a> for alloc::collections::VecDeque<T>
 where
     T: 'a,
 {
     type Item = &'a T;
-    type Iter = std::collections::vec_deque::Iter<'a, T>;
+    type Iter = alloc::collections::vec_deque::Iter<'a, T>;
     fn iter(&'a self) -> Self::Iter {
         self.iter()
     }
 }
 
-impl<'a, T> IterView<'a> for std::collections::HashSet<T>
+#[cfg(feature = "std")]
+impl<'a, T, S> IterView<'a> for std::collections::HashSet<T, S>
 where
-    T: Eq + std::hash::Hash + 'a,
+    T: 'a,
+    S: 'a,
 {
     type Item = &'a T;
     type Iter = std::collections::hash_set::Iter<'a, T>;
@@ -221,8 +443,203 @@ where
     }
 }
 
+#[cfg(feature = "alloc")]
+impl<'a, K, V> IterView<'a> for alloc::collections::BTreeMap<K, V>
+where
+    K: 'a,
+    V: 'a,
+{
+    type Item = (&'a K, &'a V);
+    type Iter = alloc::collections::btree_map::Iter<'a, K, V>;
+    fn iter(&'a self) -> Self::Iter {
+        self.iter()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a, T> IterView<'a> for alloc::collections::BTreeSet<T>
+where
+    T: 'a,
+{
+    type Item = &'a T;
+    type Iter = alloc::collections::btree_set::Iter<'a, T>;
+    fn iter(&'a self) -> Self::Iter {
+        self.iter()
+    }
+}
+
+/// `IterView` whose iterator knows its exact length, implemented for every such view automatically.
+///
+/// Methods are prefixed with `view_` so they don't clash with `ExactSizeIterator` on iterators that
+/// are views too, such as `Range`.
+pub trait ExactSizeIterView<'a>: IterView<'a, Iter: ExactSizeIterator> {
+    fn view_len(&'a self) -> usize {
+        self.iter().len()
+    }
+
+    fn view_is_empty(&'a self) -> bool {
+        self.view_len() == 0
+    }
+}
+
+impl<'a, V: IterView<'a, Iter: ExactSizeIterator> + ?Sized> ExactSizeIterView<'a> for V {}
+
+/// `IterView` whose iterator can be iterated from the back, implemented for every such view
+/// automatically.
+pub trait DoubleEndedIterView<'a>: IterView<'a, Iter: DoubleEndedIterator> {
+    fn iter_rev(&'a self) -> core::iter::Rev<Self::Iter> {
+        self.iter().rev()
+    }
+}
+
+impl<'a, V: IterView<'a, Iter: DoubleEndedIterator> + ?Sized> DoubleEndedIterView<'a> for V {}
+
+/// Like `IterView`, but return an iterator of mutable references, still not consume current object.
+///
+/// `HashSet`, `BTreeSet` and `BinaryHeap` have no `IterViewMut` impl, mutating their items in place would break
+/// their invariants.
+pub trait IterViewMut<'a> {
+    type Item: 'a;
+    type Iter: Iterator<Item = Self::Item>;
+    fn iter_mut(&'a mut self) -> Self::Iter;
+}
+
+impl<'a, T: 'a + IterViewMut<'a> + ?Sized> IterViewMut<'a> for &'a mut T {
+    type Item = T::Item;
+    type Iter = T::Iter;
+    fn iter_mut(&'a mut self) -> Self::Iter {
+        (**self).iter_mut()
+    }
+}
+
+impl<'a, T: 'a, const N: usize> IterViewMut<'a> for [T; N] {
+    type Item = &'a mut T;
+    type Iter = slice::IterMut<'a, T>;
+    fn iter_mut(&'a mut self) -> Self::Iter {
+        self[..].iter_mut()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a, T: 'a> IterViewMut<'a> for Vec<T> {
+    type Item = &'a mut T;
+    type Iter = slice::IterMut<'a, T>;
+    fn iter_mut(&'a mut self) -> Self::Iter {
+        self[..].iter_mut()
+    }
+}
+
+impl<'a, T: 'a> IterViewMut<'a> for [T] {
+    type Item = &'a mut T;
+    type Iter = slice::IterMut<'a, T>;
+    fn iter_mut(&'a mut self) -> Self::Iter {
+        self.iter_mut()
+    }
+}
+
+impl<'a, T: 'a> IterViewMut<'a> for Option<T> {
+    type Item = &'a mut T;
+    type Iter = core::option::IterMut<'a, T>;
+    fn iter_mut(&'a mut self) -> Self::Iter {
+        self.iter_mut()
+    }
+}
+
+impl<'a, T: 'a, E: 'a> IterViewMut<'a> for Result<T, E> {
+    type Item = &'a mut T;
+    type Iter = core::result::IterMut<'a, T>;
+    fn iter_mut(&'a mut self) -> Self::Iter {
+        self.iter_mut()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a, T> IterViewMut<'a> for Box<T>
+where
+    T: IterViewMut<'a> + ?Sized,
+{
+    type Item = T::Item;
+    type Iter = T::Iter;
+    fn iter_mut(&'a mut self) -> Self::Iter {
+        self.as_mut().iter_mut()
+    }
+}
+
+impl<'a, P> IterViewMut<'a> for core::pin::Pin<P>
+where
+    P: core::ops::DerefMut<Target: IterViewMut<'a> + Unpin>,
+{
+    type Item = <P::Target as IterViewMut<'a>>::Item;
+    type Iter = <P::Target as IterViewMut<'a>>::Iter;
+    fn iter_mut(&'a mut self) -> Self::Iter {
+        self.as_mut().get_mut().iter_mut()
+    }
+}
+
+impl<'a, T> IterViewMut<'a> for core::mem::ManuallyDrop<T>
+where
+    T: IterViewMut<'a> + ?Sized,
+{
+    type Item = T::Item;
+    type Iter = T::Iter;
+    fn iter_mut(&'a mut self) -> Self::Iter {
+        (**self).iter_mut()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'a, K, V, S> IterViewMut<'a> for std::collections::HashMap<K, V, S>
+where
+    K: 'a,
+    V: 'a,
+    S: 'a,
+{
+    type Item = (&'a K, &'a mut V);
+    type Iter = std::collections::hash_map::IterMut<'a, K, V>;
+    fn iter_mut(&'a mut self) -> Self::Iter {
+        self.iter_mut()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a, T> IterViewMut<'a> for alloc::collections::LinkedList<T>
+where
+    T: 'a,
+{
+    type Item = &'a mut T;
+    type Iter = alloc::collections::linked_list::IterMut<'a, T>;
+    fn iter_mut(&'a mut self) -> Self::Iter {
+        self.iter_mut()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a, T> IterViewMut<'a> for alloc::collections::VecDeque<T>
+where
+    T: 'a,
+{
+    type Item = &'a mut T;
+    type Iter = alloc::collections::vec_deque::IterMut<'a, T>;
+    fn iter_mut(&'a mut self) -> Self::Iter {
+        self.iter_mut()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a, K, V> IterViewMut<'a> for alloc::collections::BTreeMap<K, V>
+where
+    K: 'a,
+    V: 'a,
+{
+    type Item = (&'a K, &'a mut V);
+    type Iter = alloc::collections::btree_map::IterMut<'a, K, V>;
+    fn iter_mut(&'a mut self) -> Self::Iter {
+        self.iter_mut()
+    }
+}
+
 /// Using a function to iter view a value.
-pub fn iter<T, O, F, I>(o: &O, f: F) -> FuncIterView<T, O, F, I> {
+pub fn iter<T, O, F, I>(o: &O, f: F) -> FuncIterView<'_, T, O, F, I> {
     FuncIterView {
         f,
         o,
@@ -231,6 +648,7 @@ pub fn iter<T, O, F, I>(o: &O, f: F) -> FuncIterView<T, O, F, I> {
     }
 }
 
+/// View created by [`iter()`].
 pub struct FuncIterView<'a, T, O, F, I> {
     f: F,
     o: &'a O,
@@ -238,6 +656,27 @@ pub struct FuncIterView<'a, T, O, F, I> {
     i: PhantomData<I>,
 }
 
+impl<T, O, F: Clone, I> Clone for FuncIterView<'_, T, O, F, I> {
+    fn clone(&self) -> Self {
+        Self {
+            f: self.f.clone(),
+            o: self.o,
+            t: PhantomData,
+            i: PhantomData,
+        }
+    }
+}
+
+impl<T, O, F: Copy, I> Copy for FuncIterView<'_, T, O, F, I> {}
+
+impl<T, O: core::fmt::Debug, F, I> core::fmt::Debug for FuncIterView<'_, T, O, F, I> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("FuncIterView")
+            .field("o", &self.o)
+            .finish_non_exhaustive()
+    }
+}
+
 impl<'a, T, O, F, I> IterView<'a> for FuncIterView<'a, T, O, F, I>
 where
     T: 'a,
@@ -251,6 +690,103 @@ where
     }
 }
 
+/// Like [`iter()`], but the view owns the value, so it can be returned from a function.
+///
+/// Closures can't return an iterator borrowing from their argument, pass a `fn` for that:
+///
+/// ```rust
+/// use std::sync::Arc;
+/// use iter_view::IterView;
+///
+/// fn split(s: &Arc<String>) -> std::str::Split<'_, char> {
+///     s.split(' ')
+/// }
+///
+/// fn words(text: Arc<String>) -> impl for<'a> IterView<'a, Item = &'a str> {
+///     iter_view::iter_owned(text, split)
+/// }
+///
+/// let view = words(Arc::new("a b c".to_owned()));
+/// assert_eq!(view.iter().collect::<Vec<_>>(), ["a", "b", "c"]);
+/// ```
+pub fn iter_owned<O, F>(o: O, f: F) -> OwnedFuncIterView<O, F> {
+    OwnedFuncIterView { f, o }
+}
+
+/// View created by [`iter_owned()`].
+#[derive(Clone, Copy)]
+pub struct OwnedFuncIterView<O, F> {
+    f: F,
+    o: O,
+}
+
+impl<O, F> OwnedFuncIterView<O, F> {
+    pub fn into_inner(self) -> O {
+        self.o
+    }
+}
+
+impl<O: core::fmt::Debug, F> core::fmt::Debug for OwnedFuncIterView<O, F> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("OwnedFuncIterView")
+            .field("o", &self.o)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'a, O, F, I> IterView<'a> for OwnedFuncIterView<O, F>
+where
+    O: 'a,
+    F: Fn(&'a O) -> I,
+    I: Iterator<Item: 'a>,
+{
+    type Item = I::Item;
+    type Iter = I;
+    fn iter(&'a self) -> Self::Iter {
+        (self.f)(&self.o)
+    }
+}
+
+/// Creates a view from a closure without backing object, each `iter()` calls the closure.
+///
+/// ```rust
+/// use iter_view::IterView;
+///
+/// let view = iter_view::from_fn(|| (1..=3).map(|v| v * v));
+/// assert_eq!(view.iter().sum::<i32>(), 14);
+/// ```
+pub fn from_fn<F, I>(f: F) -> FromFnView<F>
+where
+    F: Fn() -> I,
+    I: Iterator,
+{
+    FromFnView { f }
+}
+
+/// View created by [`from_fn()`].
+#[derive(Clone, Copy)]
+pub struct FromFnView<F> {
+    f: F,
+}
+
+impl<F> core::fmt::Debug for FromFnView<F> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("FromFnView").finish_non_exhaustive()
+    }
+}
+
+impl<'a, F, I> IterView<'a> for FromFnView<F>
+where
+    F: Fn() -> I,
+    I: Iterator<Item: 'a>,
+{
+    type Item = I::Item;
+    type Iter = I;
+    fn iter(&'a self) -> Self::Iter {
+        (self.f)()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -260,6 +796,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "alloc")]
     fn iter_vec() {
         let v = vec![1, 2, 3];
         let mut iter = iter_view(&v);
@@ -269,6 +806,184 @@ mod tests {
         assert_eq!(iter.next(), None);
     }
 
+    fn iter_view_mut<'a, T: IterViewMut<'a> + ?Sized>(o: &'a mut T) -> T::Iter {
+        o.iter_mut()
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn iter_mut_vec() {
+        let mut v = vec![1, 2, 3];
+        for item in iter_view_mut(&mut v) {
+            *item *= 2;
+        }
+        assert_eq!(v, [2, 4, 6]);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn iter_mut_hash_map() {
+        let mut m: std::collections::HashMap<_, _> = [("a", 1), ("b", 2)].into_iter().collect();
+        for (_, v) in iter_view_mut(&mut m) {
+            *v += 10;
+        }
+        assert_eq!(m["a"], 11);
+        assert_eq!(m["b"], 12);
+    }
+
+    #[test]
+    fn iter_mut_option() {
+        let mut v = Some(1);
+        for item in iter_view_mut(&mut v) {
+            *item = 5;
+        }
+        assert_eq!(v, Some(5));
+
+        let mut v: Option<i32> = None;
+        assert_eq!(iter_view_mut(&mut v).next(), None);
+    }
+
+    #[cfg(feature = "alloc")]
+    fn last_two<'a, V: DoubleEndedIterView<'a> + ExactSizeIterView<'a> + ?Sized>(
+        v: &'a V,
+    ) -> (usize, Vec<V::Item>) {
+        (v.view_len(), v.iter_rev().take(2).collect())
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn capabilities() {
+        let v: std::collections::VecDeque<_> = [1, 2, 3].into_iter().collect();
+        assert_eq!(last_two(&v), (3, vec![&3, &2]));
+        assert_eq!(last_two(&[1u8; 0]), (0, vec![]));
+        assert!(None::<i32>.view_is_empty());
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn iter_hash_map_with_hasher() {
+        use std::hash::BuildHasherDefault;
+        let mut m: std::collections::HashMap<_, _, BuildHasherDefault<std::hash::DefaultHasher>> =
+            Default::default();
+        m.insert(1, 2);
+        let mut iter = iter_view(&m);
+        assert_eq!(iter.next(), Some((&1, &2)));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn iter_btree() {
+        let m: std::collections::BTreeMap<_, _> = [(2, "b"), (1, "a")].into_iter().collect();
+        let mut iter = iter_view(&m);
+        assert_eq!(iter.next(), Some((&1, &"a")));
+        assert_eq!(iter.next(), Some((&2, &"b")));
+        assert_eq!(iter.next(), None);
+
+        let s: std::collections::BTreeSet<_> = [2, 1].into_iter().collect();
+        let mut iter = iter_view(&s);
+        assert_eq!(iter.next(), Some(&1));
+        assert_eq!(iter.next(), Some(&2));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn iter_box() {
+        let v: Box<[u8]> = Box::new([1, 2]);
+        assert_eq!(iter_view(&v).collect::<Vec<_>>(), [&1, &2]);
+
+        let mut v: Box<Vec<u8>> = Box::new(vec![1, 2]);
+        iter_view_mut(&mut v).for_each(|v| *v += 1);
+        assert_eq!(*v, [2, 3]);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn iter_rc() {
+        let v: std::rc::Rc<[u8]> = std::rc::Rc::new([1, 2]);
+        assert_eq!(iter_view(&v).collect::<Vec<_>>(), [&1, &2]);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn iter_arc() {
+        let v = std::sync::Arc::new(vec![1, 2]);
+        assert_eq!(iter_view(&v).collect::<Vec<_>>(), [&1, &2]);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn iter_cow() {
+        use std::borrow::Cow;
+
+        let v: Cow<'_, [u8]> = Cow::Borrowed(&[1, 2]);
+        assert_eq!(iter_view(&v).collect::<Vec<_>>(), [&1, &2]);
+        let v: Cow<'_, [u8]> = Cow::Owned(vec![3]);
+        assert_eq!(iter_view(&v).collect::<Vec<_>>(), [&3]);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn iter_pin() {
+        let mut v = std::pin::Pin::new(Box::new(vec![1, 2]));
+        assert_eq!(iter_view(&v).collect::<Vec<_>>(), [&1, &2]);
+        iter_view_mut(&mut v).for_each(|v| *v += 1);
+        assert_eq!(iter_view(&v).collect::<Vec<_>>(), [&2, &3]);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn iter_manually_drop() {
+        let mut v = std::mem::ManuallyDrop::new(vec![1, 2]);
+        iter_view_mut(&mut v).for_each(|v| *v += 1);
+        assert_eq!(iter_view(&v).collect::<Vec<_>>(), [&2, &3]);
+        drop(std::mem::ManuallyDrop::into_inner(v));
+    }
+
+    #[cfg(all(feature = "derive", feature = "alloc"))]
+    #[test]
+    fn derive() {
+        #[derive(IterView, IterViewMut)]
+        struct Ids(Vec<u32>);
+
+        fn name(member: &(String, u32)) -> &str {
+            &member.0
+        }
+
+        #[derive(IterView, IterViewMut)]
+        struct Roster<T> {
+            #[iter_view(map = name, item = &'view str)]
+            members: Vec<(String, u32)>,
+            _extra: T,
+        }
+
+        #[derive(IterView)]
+        struct Tagged<C> {
+            _tag: &'static str,
+            #[iter_view]
+            inner: C,
+        }
+
+        let tagged = Tagged {
+            _tag: "t",
+            inner: std::collections::BTreeSet::from([2, 1]),
+        };
+        assert_eq!(iter_view(&tagged).collect::<Vec<_>>(), [&1, &2]);
+
+        let mut ids = Ids(vec![1, 2]);
+        iter_view_mut(&mut ids).for_each(|v| *v += 1);
+        assert_eq!(iter_view(&ids).collect::<Vec<_>>(), [&2, &3]);
+
+        let mut roster = Roster {
+            members: vec![("a".to_owned(), 1), ("b".to_owned(), 2)],
+            _extra: (),
+        };
+        iter_view_mut(&mut roster).for_each(|m| m.1 += 1);
+        assert_eq!(iter_view(&roster).collect::<Vec<_>>(), ["a", "b"]);
+        assert_eq!(roster.members[1].1, 3);
+    }
+
     #[test]
     fn iter_option() {
         let mut v = Some(1);
@@ -302,6 +1017,33 @@ mod tests {
         assert_eq!(vals.next(), None);
     }
 
+    #[test]
+    fn iter_func_owned() {
+        fn items(v: &std::sync::Arc<Vec<i32>>) -> std::slice::Iter<'_, i32> {
+            v.iter()
+        }
+        let view = iter_owned(std::sync::Arc::new(vec![1, 2, 3]), items);
+        let cloned = view.clone();
+        assert_eq!(iter_view(&view).collect::<Vec<_>>(), [&1, &2, &3]);
+        assert_eq!(iter_view(&cloned).sum::<i32>(), 6);
+        assert_eq!(
+            format!("{view:?}"),
+            "OwnedFuncIterView { o: [1, 2, 3], .. }"
+        );
+        assert_eq!(view.into_inner().len(), 3);
+
+        let view = from_fn(|| "ab".chars());
+        let copied = view;
+        assert_eq!(iter_view(&view).collect::<String>(), "ab");
+        assert_eq!(iter_view(&copied).count(), 2);
+
+        let v = 3;
+        let view = iter(&v, |v: &i32| 0..*v);
+        let copied = view;
+        assert_eq!(iter_view(&view).count(), iter_view(&copied).count());
+        assert_eq!(format!("{view:?}"), "FuncIterView { o: 3, .. }");
+    }
+
     #[test]
     fn iter_slice() {
         let v: &[u8] = &[1, 2, 3];
@@ -0,0 +1,256 @@
+//! Re-iterable views over one-shot iterators, see [`MemoView`].
+
+use alloc::boxed::Box;
+use core::cell::{OnceCell, RefCell};
+use core::iter::{Fuse, FusedIterator};
+
+use crate::IterView;
+
+/// Turns an `Iterator` into a view, items are pulled from the iterator on first traversal and kept,
+/// later traversals replay them.
+///
+/// Items are kept in a linked list of boxed nodes, so references to them stay valid while more
+/// items are pulled. All items are kept until the view is dropped. Use [`SyncMemoView`] to share
+/// across threads.
+///
+/// Panics if the source iterator iterates the view itself.
+///
+/// ```rust
+/// use iter_view::{IterView, MemoView};
+///
+/// let lines = MemoView::new("a\nb\nc".lines());
+/// assert_eq!(lines.iter().next(), Some(&"a"));
+/// assert_eq!(lines.iter().count(), 3);
+/// ```
+pub struct MemoView<I: Iterator> {
+    source: RefCell<Fuse<I>>,
+    head: OnceCell<Box<Node<I::Item>>>,
+}
+
+struct Node<T> {
+    item: T,
+    next: OnceCell<Box<Node<T>>>,
+}
+
+impl<I: Iterator> MemoView<I> {
+    pub fn new(source: I) -> Self {
+        Self {
+            source: RefCell::new(source.fuse()),
+            head: OnceCell::new(),
+        }
+    }
+}
+
+impl<I: Iterator> Drop for MemoView<I> {
+    fn drop(&mut self) {
+        // Drop nodes one by one, the default drop recurses over the list.
+        let mut next = self.head.take();
+        while let Some(mut node) = next {
+            next = node.next.take();
+        }
+    }
+}
+
+impl<'a, I> IterView<'a> for MemoView<I>
+where
+    I: Iterator<Item: 'a> + 'a,
+{
+    type Item = &'a I::Item;
+    type Iter = MemoIter<'a, I>;
+    fn iter(&'a self) -> Self::Iter {
+        MemoIter {
+            view: self,
+            slot: &self.head,
+        }
+    }
+}
+
+/// Iterator of [`MemoView`].
+pub struct MemoIter<'a, I: Iterator> {
+    view: &'a MemoView<I>,
+    /// Where the next item lives, empty if not pulled from the source yet.
+    slot: &'a OnceCell<Box<Node<I::Item>>>,
+}
+
+impl<I: Iterator> Clone for MemoIter<'_, I> {
+    fn clone(&self) -> Self {
+        Self {
+            view: self.view,
+            slot: self.slot,
+        }
+    }
+}
+
+impl<'a, I: Iterator> Iterator for MemoIter<'a, I> {
+    type Item = &'a I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = match self.slot.get() {
+            Some(node) => node,
+            None => {
+                let item = self.view.source.borrow_mut().next()?;
+                self.slot.get_or_init(|| Box::new(Node::new(item)))
+            }
+        };
+        self.slot = &node.next;
+        Some(&node.item)
+    }
+}
+
+impl<I: Iterator> FusedIterator for MemoIter<'_, I> {}
+
+impl<T> Node<T> {
+    fn new(item: T) -> Self {
+        Self {
+            item,
+            next: OnceCell::new(),
+        }
+    }
+}
+
+/// Thread safe flavor of [`MemoView`], the source iterator is behind a `Mutex`.
+///
+/// Deadlocks or panics if the source iterator iterates the view itself, the `Mutex` is held while
+/// pulling from it.
+#[cfg(feature = "std")]
+pub struct SyncMemoView<I: Iterator> {
+    source: std::sync::Mutex<Fuse<I>>,
+    head: std::sync::OnceLock<Box<SyncNode<I::Item>>>,
+}
+
+#[cfg(feature = "std")]
+struct SyncNode<T> {
+    item: T,
+    next: std::sync::OnceLock<Box<SyncNode<T>>>,
+}
+
+#[cfg(feature = "std")]
+impl<I: Iterator> SyncMemoView<I> {
+    pub fn new(source: I) -> Self {
+        Self {
+            source: std::sync::Mutex::new(source.fuse()),
+            head: std::sync::OnceLock::new(),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<I: Iterator> Drop for SyncMemoView<I> {
+    fn drop(&mut self) {
+        let mut next = self.head.take();
+        while let Some(mut node) = next {
+            next = node.next.take();
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'a, I> IterView<'a> for SyncMemoView<I>
+where
+    I: Iterator<Item: 'a> + 'a,
+{
+    type Item = &'a I::Item;
+    type Iter = SyncMemoIter<'a, I>;
+    fn iter(&'a self) -> Self::Iter {
+        SyncMemoIter {
+            view: self,
+            slot: &self.head,
+        }
+    }
+}
+
+/// Iterator of [`SyncMemoView`].
+#[cfg(feature = "std")]
+pub struct SyncMemoIter<'a, I: Iterator> {
+    view: &'a SyncMemoView<I>,
+    slot: &'a std::sync::OnceLock<Box<SyncNode<I::Item>>>,
+}
+
+#[cfg(feature = "std")]
+impl<I: Iterator> Clone for SyncMemoIter<'_, I> {
+    fn clone(&self) -> Self {
+        Self {
+            view: self.view,
+            slot: self.slot,
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'a, I: Iterator> Iterator for SyncMemoIter<'a, I> {
+    type Item = &'a I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = match self.slot.get() {
+            Some(node) => node,
+            None => {
+                let mut source = self.view.source.lock().unwrap_or_else(|e| e.into_inner());
+                // Another thread may have pulled the item while waiting for the lock.
+                match self.slot.get() {
+                    Some(node) => node,
+                    None => {
+                        let item = source.next()?;
+                        self.slot.get_or_init(|| {
+                            Box::new(SyncNode {
+                                item,
+                                next: std::sync::OnceLock::new(),
+                            })
+                        })
+                    }
+                }
+            }
+        };
+        self.slot = &node.next;
+        Some(&node.item)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<I: Iterator> FusedIterator for SyncMemoIter<'_, I> {}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use super::*;
+
+    #[test]
+    fn replay() {
+        let pulled = Cell::new(0);
+        let view = MemoView::new((1..=3).inspect(|_| pulled.set(pulled.get() + 1)));
+        let mut a = view.iter();
+        assert_eq!(a.next(), Some(&1));
+        assert_eq!(pulled.get(), 1);
+
+        let mut b = view.iter();
+        assert_eq!(b.next(), Some(&1));
+        assert_eq!(b.next(), Some(&2));
+        assert_eq!(a.next(), Some(&2));
+        assert_eq!(pulled.get(), 2);
+        assert_eq!(view.iter().collect::<Vec<_>>(), [&1, &2, &3]);
+        assert_eq!(a.next(), Some(&3));
+        assert_eq!(a.next(), None);
+        assert_eq!(pulled.get(), 3);
+    }
+
+    #[test]
+    fn long_list() {
+        let view = MemoView::new(0..1_000_000);
+        assert_eq!(view.iter().count(), 1_000_000);
+        drop(view);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn sync() {
+        let view = SyncMemoView::new((0..10_000).map(|v| v.to_string()));
+        let sums: Vec<usize> = std::thread::scope(|s| {
+            let handles: Vec<_> = (0..4)
+                .map(|_| s.spawn(|| view.iter().map(|v| v.len()).sum()))
+                .collect();
+            handles.into_iter().map(|h| h.join().unwrap()).collect()
+        });
+        assert!(sums.iter().all(|v| *v == sums[0]));
+        assert_eq!(view.iter().nth(9_999).map(String::as_str), Some("9999"));
+    }
+}
@@ -0,0 +1,221 @@
+//! Parallel flavor of [`IterView`], enabled by the `rayon` feature.
+
+use std::collections::{BTreeMap, BTreeSet, BinaryHeap, HashMap, HashSet, LinkedList, VecDeque};
+
+use rayon::iter::{IntoParallelIterator, IterBridge, ParallelBridge, ParallelIterator};
+
+use crate::IterView;
+
+/// Like `IterView`, but return a rayon `ParallelIterator`, the same collection can be scanned in
+/// parallel many times.
+///
+/// Use [`ParBridge`] for views without a native parallel iterator.
+pub trait ParIterView<'a> {
+    type Item: Send + 'a;
+    type Iter: ParallelIterator<Item = Self::Item>;
+    fn par_iter(&'a self) -> Self::Iter;
+}
+
+impl<'a, T: 'a + ParIterView<'a> + ?Sized> ParIterView<'a> for &'a T {
+    type Item = T::Item;
+    type Iter = T::Iter;
+    fn par_iter(&'a self) -> Self::Iter {
+        (**self).par_iter()
+    }
+}
+
+impl<'a, T> ParIterView<'a> for Box<T>
+where
+    T: ParIterView<'a> + ?Sized,
+{
+    type Item = T::Item;
+    type Iter = T::Iter;
+    fn par_iter(&'a self) -> Self::Iter {
+        (**self).par_iter()
+    }
+}
+
+impl<'a, T> ParIterView<'a> for std::sync::Arc<T>
+where
+    T: ParIterView<'a> + ?Sized,
+{
+    type Item = T::Item;
+    type Iter = T::Iter;
+    fn par_iter(&'a self) -> Self::Iter {
+        (**self).par_iter()
+    }
+}
+
+impl<'a, T: Sync + 'a, const N: usize> ParIterView<'a> for [T; N] {
+    type Item = &'a T;
+    type Iter = rayon::slice::Iter<'a, T>;
+    fn par_iter(&'a self) -> Self::Iter {
+        self[..].into_par_iter()
+    }
+}
+
+impl<'a, T: Sync + 'a> ParIterView<'a> for Vec<T> {
+    type Item = &'a T;
+    type Iter = rayon::slice::Iter<'a, T>;
+    fn par_iter(&'a self) -> Self::Iter {
+        self[..].into_par_iter()
+    }
+}
+
+impl<'a, T: Sync + 'a> ParIterView<'a> for [T] {
+    type Item = &'a T;
+    type Iter = rayon::slice::Iter<'a, T>;
+    fn par_iter(&'a self) -> Self::Iter {
+        self.into_par_iter()
+    }
+}
+
+impl<'a, T: Sync + 'a> ParIterView<'a> for Option<T> {
+    type Item = &'a T;
+    type Iter = rayon::option::Iter<'a, T>;
+    fn par_iter(&'a self) -> Self::Iter {
+        self.into_par_iter()
+    }
+}
+
+impl<'a, T: Sync + 'a, E: 'a> ParIterView<'a> for Result<T, E> {
+    type Item = &'a T;
+    type Iter = rayon::result::Iter<'a, T>;
+    fn par_iter(&'a self) -> Self::Iter {
+        self.into_par_iter()
+    }
+}
+
+impl<'a, K, V, S> ParIterView<'a> for HashMap<K, V, S>
+where
+    K: Sync + 'a,
+    V: Sync + 'a,
+    S: 'a,
+{
+    type Item = (&'a K, &'a V);
+    type Iter = rayon::collections::hash_map::Iter<'a, K, V>;
+    fn par_iter(&'a self) -> Self::Iter {
+        self.into_par_iter()
+    }
+}
+
+impl<'a, T> ParIterView<'a> for LinkedList<T>
+where
+    T: Sync + 'a,
+{
+    type Item = &'a T;
+    type Iter = rayon::collections::linked_list::Iter<'a, T>;
+    fn par_iter(&'a self) -> Self::Iter {
+        self.into_par_iter()
+    }
+}
+
+impl<'a, T> ParIterView<'a> for BinaryHeap<T>
+where
+    T: Sync + 'a,
+{
+    type Item = &'a T;
+    type Iter = rayon::collections::binary_heap::Iter<'a, T>;
+    fn par_iter(&'a self) -> Self::Iter {
+        self.into_par_iter()
+    }
+}
+
+impl<'a, T> ParIterView<'a> for VecDeque<T>
+where
+    T: Sync + 'a,
+{
+    type Item = &'a T;
+    type Iter = rayon::collections::vec_deque::Iter<'a, T>;
+    fn par_iter(&'a self) -> Self::Iter {
+        self.into_par_iter()
+    }
+}
+
+impl<'a, T, S> ParIterView<'a> for HashSet<T, S>
+where
+    T: Sync + 'a,
+    S: 'a,
+{
+    type Item = &'a T;
+    type Iter = rayon::collections::hash_set::Iter<'a, T>;
+    fn par_iter(&'a self) -> Self::Iter {
+        self.into_par_iter()
+    }
+}
+
+impl<'a, K, V> ParIterView<'a> for BTreeMap<K, V>
+where
+    K: Sync + 'a,
+    V: Sync + 'a,
+{
+    type Item = (&'a K, &'a V);
+    type Iter = rayon::collections::btree_map::Iter<'a, K, V>;
+    fn par_iter(&'a self) -> Self::Iter {
+        self.into_par_iter()
+    }
+}
+
+impl<'a, T> ParIterView<'a> for BTreeSet<T>
+where
+    T: Sync + 'a,
+{
+    type Item = &'a T;
+    type Iter = rayon::collections::btree_set::Iter<'a, T>;
+    fn par_iter(&'a self) -> Self::Iter {
+        self.into_par_iter()
+    }
+}
+
+/// Wraps any `IterView` whose iterator and items are `Send` as a `ParIterView`, the sequential
+/// iterator is bridged by `ParallelBridge::par_bridge()`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ParBridge<V>(pub V);
+
+impl<'a, V> ParIterView<'a> for ParBridge<V>
+where
+    V: IterView<'a>,
+    V::Iter: Send,
+    V::Item: Send,
+{
+    type Item = V::Item;
+    type Iter = IterBridge<V::Iter>;
+    fn par_iter(&'a self) -> Self::Iter {
+        self.0.iter().par_bridge()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn par_sum<'a, V: ParIterView<'a, Item = &'a i32> + ?Sized>(v: &'a V) -> i32 {
+        v.par_iter().sum()
+    }
+
+    #[test]
+    fn par_vec() {
+        let v: Vec<i32> = (1..=100).collect();
+        assert_eq!(par_sum(&v), 5050);
+        assert_eq!(par_sum(&v), 5050);
+    }
+
+    #[test]
+    fn par_hash_map() {
+        let m: HashMap<_, _> = (0..10).map(|i| (i, i * 2)).collect();
+        assert_eq!(m.par_iter().map(|(_, v)| v).sum::<i32>(), 90);
+        let s: HashSet<i32> = (1..=4).collect();
+        assert_eq!(par_sum(&s), 10);
+        let d: VecDeque<i32> = (1..=4).collect();
+        assert_eq!(par_sum(&d), 10);
+    }
+
+    #[test]
+    fn par_bridge() {
+        let n = 4;
+        let view = ParBridge(crate::iter(&n, |n: &i32| 1..=*n));
+        let mut items: Vec<_> = view.par_iter().collect();
+        items.sort();
+        assert_eq!(items, [1, 2, 3, 4]);
+    }
+}
@@ -0,0 +1,115 @@
+//! Views over a key range of ordered collections.
+
+use alloc::collections::{btree_map, btree_set, BTreeMap, BTreeSet};
+use core::borrow::Borrow;
+use core::marker::PhantomData;
+use core::ops::RangeBounds;
+
+use crate::IterView;
+
+/// Ordered collections that can create a view of a sub range of their keys.
+pub trait RangeView<Q: ?Sized> {
+    /// Returns a view of the items whose keys are in `range`, the view can be iterated many times.
+    ///
+    /// Panics on iterating if `range` start is greater than end, or start equals end and both are
+    /// excluded, same as `BTreeMap::range()`.
+    fn range_view<R: RangeBounds<Q> + Clone>(&self, range: R) -> BTreeRangeView<'_, Self, Q, R>;
+}
+
+impl<K, V, Q> RangeView<Q> for BTreeMap<K, V>
+where
+    K: Borrow<Q> + Ord,
+    Q: Ord + ?Sized,
+{
+    fn range_view<R: RangeBounds<Q> + Clone>(&self, range: R) -> BTreeRangeView<'_, Self, Q, R> {
+        BTreeRangeView::new(self, range)
+    }
+}
+
+impl<T, Q> RangeView<Q> for BTreeSet<T>
+where
+    T: Borrow<Q> + Ord,
+    Q: Ord + ?Sized,
+{
+    fn range_view<R: RangeBounds<Q> + Clone>(&self, range: R) -> BTreeRangeView<'_, Self, Q, R> {
+        BTreeRangeView::new(self, range)
+    }
+}
+
+/// View of a key range of `BTreeMap` or `BTreeSet`, created by [`RangeView::range_view()`].
+pub struct BTreeRangeView<'a, C: ?Sized, Q: ?Sized, R> {
+    collection: &'a C,
+    range: R,
+    q: PhantomData<fn(&Q)>,
+}
+
+impl<'a, C: ?Sized, Q: ?Sized, R> BTreeRangeView<'a, C, Q, R> {
+    fn new(collection: &'a C, range: R) -> Self {
+        Self {
+            collection,
+            range,
+            q: PhantomData,
+        }
+    }
+}
+
+impl<C: ?Sized, Q: ?Sized, R: Clone> Clone for BTreeRangeView<'_, C, Q, R> {
+    fn clone(&self) -> Self {
+        Self::new(self.collection, self.range.clone())
+    }
+}
+
+impl<'a, K, V, Q, R> IterView<'a> for BTreeRangeView<'a, BTreeMap<K, V>, Q, R>
+where
+    K: Borrow<Q> + Ord + 'a,
+    V: 'a,
+    Q: Ord + ?Sized,
+    R: RangeBounds<Q> + Clone,
+{
+    type Item = (&'a K, &'a V);
+    type Iter = btree_map::Range<'a, K, V>;
+    fn iter(&self) -> Self::Iter {
+        self.collection.range(self.range.clone())
+    }
+}
+
+impl<'a, T, Q, R> IterView<'a> for BTreeRangeView<'a, BTreeSet<T>, Q, R>
+where
+    T: Borrow<Q> + Ord + 'a,
+    Q: Ord + ?Sized,
+    R: RangeBounds<Q> + Clone,
+{
+    type Item = &'a T;
+    type Iter = btree_set::Range<'a, T>;
+    fn iter(&self) -> Self::Iter {
+        self.collection.range(self.range.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn map_range() {
+        let m: BTreeMap<_, _> = [(1, "a"), (2, "b"), (3, "c"), (4, "d")]
+            .into_iter()
+            .collect();
+        let view = m.range_view(2..4);
+        for _ in 0..2 {
+            let mut iter = view.iter();
+            assert_eq!(iter.next(), Some((&2, &"b")));
+            assert_eq!(iter.next(), Some((&3, &"c")));
+            assert_eq!(iter.next(), None);
+        }
+    }
+
+    #[test]
+    fn set_range() {
+        use std::ops::Bound::{Excluded, Unbounded};
+
+        let s: BTreeSet<String> = ["a", "b", "c"].into_iter().map(String::from).collect();
+        let view = RangeView::<str>::range_view(&s, (Excluded("a"), Unbounded));
+        assert_eq!(view.iter().collect::<Vec<_>>(), ["b", "c"]);
+    }
+}
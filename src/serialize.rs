@@ -0,0 +1,88 @@
+//! Serialize views without collecting, enabled by the `serde` feature.
+
+use core::marker::PhantomData;
+
+use serde::{Serialize, Serializer};
+
+use crate::IterView;
+
+/// Serialize items of the view as a sequence, format marker of [`SerializeView`].
+#[derive(Clone, Copy, Debug)]
+pub enum SeqFormat {}
+
+/// Serialize `(key, value)` items of the view as a map, format marker of [`SerializeView`].
+#[derive(Clone, Copy, Debug)]
+pub enum MapFormat {}
+
+/// Implements `Serialize` for a borrowed view, created by [`as_seq()`] or [`as_map()`].
+pub struct SerializeView<'a, V: ?Sized, Format> {
+    view: &'a V,
+    format: PhantomData<Format>,
+}
+
+impl<V: ?Sized, Format> Clone for SerializeView<'_, V, Format> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<V: ?Sized, Format> Copy for SerializeView<'_, V, Format> {}
+
+/// Serialize items of the view as a sequence.
+pub fn as_seq<V: ?Sized>(view: &V) -> SerializeView<'_, V, SeqFormat> {
+    SerializeView {
+        view,
+        format: PhantomData,
+    }
+}
+
+/// Serialize `(key, value)` items of the view as a map, such as the items of `HashMap`.
+pub fn as_map<V: ?Sized>(view: &V) -> SerializeView<'_, V, MapFormat> {
+    SerializeView {
+        view,
+        format: PhantomData,
+    }
+}
+
+impl<'a, V> Serialize for SerializeView<'a, V, SeqFormat>
+where
+    V: IterView<'a> + ?Sized,
+    V::Item: Serialize,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.view.iter())
+    }
+}
+
+impl<'a, V, K, T> Serialize for SerializeView<'a, V, MapFormat>
+where
+    V: IterView<'a, Item = (K, T)> + ?Sized,
+    K: Serialize,
+    T: Serialize,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_map(self.view.iter())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::{BTreeMap, HashSet};
+
+    #[test]
+    fn seq() {
+        let s: HashSet<_> = ["a"].into_iter().collect();
+        assert_eq!(serde_json::to_string(&as_seq(&s)).unwrap(), r#"["a"]"#);
+
+        let view = crate::iter(&3, |n: &u32| 0..*n);
+        assert_eq!(serde_json::to_string(&as_seq(&view)).unwrap(), "[0,1,2]");
+    }
+
+    #[test]
+    fn map() {
+        let m: BTreeMap<_, _> = [("b", 2), ("a", 1)].into_iter().collect();
+        let json = serde_json::to_string(&as_map(&m)).unwrap();
+        assert_eq!(json, r#"{"a":1,"b":2}"#);
+    }
+}
@@ -0,0 +1,538 @@
+//! Views known to iterate in ascending order, and linear time set algebra over them.
+//!
+//! Every combinator here takes the input views by value and returns a view, pass references to keep
+//! using the inputs. The inputs are not checked, a view that claims to be sorted but isn't produces
+//! unspecified, but memory safe, results.
+
+#[cfg(feature = "alloc")]
+use alloc::collections::{binary_heap::PeekMut, BTreeMap, BTreeSet, BinaryHeap};
+use core::cmp::Ordering;
+use core::iter::Peekable;
+
+use crate::{FilterView, IndexedView, IterView, SkipView, SliceView, StepByView, TakeView};
+
+/// `IterView` whose `iter()` yields items in ascending order, equal items are allowed.
+///
+/// This is a promise of the implementation, not checked by the compiler. Use [`try_sorted()`] or
+/// [`assume_sorted()`] to mark other views as sorted.
+pub trait SortedView<'a>: IterView<'a, Item: Ord> {
+    /// Binary searches `x`, same as `slice::binary_search()`.
+    fn binary_search(&'a self, x: &Self::Item) -> Result<usize, usize>
+    where
+        Self: IndexedView<'a>,
+    {
+        self.binary_search_by(|v| v.cmp(x))
+    }
+
+    /// Returns true if the view contains `x`, in O(log n).
+    fn contains(&'a self, x: &Self::Item) -> bool
+    where
+        Self: IndexedView<'a>,
+    {
+        SortedView::binary_search(self, x).is_ok()
+    }
+}
+
+impl<'a, T: SortedView<'a> + ?Sized> SortedView<'a> for &'a T {}
+
+impl<'a, T: Ord + 'a> SortedView<'a> for Option<T> {}
+
+#[cfg(feature = "alloc")]
+impl<'a, T: Ord + 'a> SortedView<'a> for BTreeSet<T> {}
+
+#[cfg(feature = "alloc")]
+impl<'a, K: Ord + 'a, V: Ord + 'a> SortedView<'a> for BTreeMap<K, V> {}
+
+#[cfg(feature = "alloc")]
+impl<'a, T, Q, R> SortedView<'a> for crate::BTreeRangeView<'a, BTreeSet<T>, Q, R>
+where
+    T: core::borrow::Borrow<Q> + Ord + 'a,
+    Q: Ord + ?Sized,
+    R: core::ops::RangeBounds<Q> + Clone,
+{
+}
+
+#[cfg(feature = "alloc")]
+impl<'a, K, V, Q, R> SortedView<'a> for crate::BTreeRangeView<'a, BTreeMap<K, V>, Q, R>
+where
+    K: core::borrow::Borrow<Q> + Ord + 'a,
+    V: Ord + 'a,
+    Q: Ord + ?Sized,
+    R: core::ops::RangeBounds<Q> + Clone,
+{
+}
+
+impl<'s, 'a: 's, V> SortedView<'s> for SliceView<'a, V> where
+    V: IndexedView<'a> + SortedView<'a> + ?Sized
+{
+}
+
+impl<'a, V, P> SortedView<'a> for FilterView<V, P>
+where
+    V: SortedView<'a>,
+    P: Fn(&V::Item) -> bool + 'a,
+{
+}
+
+impl<'a, V: SortedView<'a>> SortedView<'a> for TakeView<V> {}
+
+impl<'a, V: SortedView<'a>> SortedView<'a> for SkipView<V> {}
+
+impl<'a, V: SortedView<'a>> SortedView<'a> for StepByView<V> {}
+
+/// A view marked as sorted, created by [`try_sorted()`] or [`assume_sorted()`].
+#[derive(Clone, Copy, Debug)]
+pub struct Sorted<V>(V);
+
+impl<V> Sorted<V> {
+    pub fn into_inner(self) -> V {
+        self.0
+    }
+}
+
+/// Marks `view` as sorted if its items are in ascending order, checked in O(n).
+pub fn try_sorted<'a, V>(view: &'a V) -> Option<Sorted<&'a V>>
+where
+    V: IterView<'a, Item: Ord> + ?Sized,
+{
+    view.iter().is_sorted().then_some(Sorted(view))
+}
+
+/// Marks `view` as sorted without checking, except in debug builds.
+pub fn assume_sorted<'a, V>(view: &'a V) -> Sorted<&'a V>
+where
+    V: IterView<'a, Item: Ord> + ?Sized,
+{
+    debug_assert!(view.iter().is_sorted(), "assume_sorted() view not sorted");
+    Sorted(view)
+}
+
+impl<'a, V: IterView<'a>> IterView<'a> for Sorted<V> {
+    type Item = V::Item;
+    type Iter = V::Iter;
+    fn iter(&'a self) -> Self::Iter {
+        self.0.iter()
+    }
+}
+
+impl<'a, V: IndexedView<'a>> IndexedView<'a> for Sorted<V> {
+    fn get(&'a self, index: usize) -> Option<Self::Item> {
+        self.0.get(index)
+    }
+}
+
+impl<'a, V: IterView<'a, Item: Ord>> SortedView<'a> for Sorted<V> {}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum SetOp {
+    Union,
+    Intersection,
+    Difference,
+    SymmetricDifference,
+}
+
+/// Items in either view, equal items of the two views are yielded once, from `a`.
+pub fn union<'a, A, B>(a: A, b: B) -> SetView<A, B>
+where
+    A: SortedView<'a>,
+    B: SortedView<'a, Item = A::Item>,
+{
+    SetView::new(a, b, SetOp::Union)
+}
+
+/// Items in both views, yielded from `a`.
+pub fn intersection<'a, A, B>(a: A, b: B) -> SetView<A, B>
+where
+    A: SortedView<'a>,
+    B: SortedView<'a, Item = A::Item>,
+{
+    SetView::new(a, b, SetOp::Intersection)
+}
+
+/// Items of `a` not in `b`.
+pub fn difference<'a, A, B>(a: A, b: B) -> SetView<A, B>
+where
+    A: SortedView<'a>,
+    B: SortedView<'a, Item = A::Item>,
+{
+    SetView::new(a, b, SetOp::Difference)
+}
+
+/// Items in exactly one of the views.
+pub fn symmetric_difference<'a, A, B>(a: A, b: B) -> SetView<A, B>
+where
+    A: SortedView<'a>,
+    B: SortedView<'a, Item = A::Item>,
+{
+    SetView::new(a, b, SetOp::SymmetricDifference)
+}
+
+/// View created by [`union()`], [`intersection()`], [`difference()`] and
+/// [`symmetric_difference()`].
+///
+/// Each item of an input is matched with at most one equal item of the other input, same as
+/// `BTreeSet` set operations.
+#[derive(Clone, Copy, Debug)]
+pub struct SetView<A, B> {
+    a: A,
+    b: B,
+    op: SetOp,
+}
+
+impl<A, B> SetView<A, B> {
+    fn new(a: A, b: B, op: SetOp) -> Self {
+        Self { a, b, op }
+    }
+}
+
+impl<'a, A, B> IterView<'a> for SetView<A, B>
+where
+    A: SortedView<'a>,
+    B: SortedView<'a, Item = A::Item>,
+{
+    type Item = A::Item;
+    type Iter = SetIter<A::Iter, B::Iter>;
+    fn iter(&'a self) -> Self::Iter {
+        SetIter {
+            a: self.a.iter().peekable(),
+            b: self.b.iter().peekable(),
+            op: self.op,
+        }
+    }
+}
+
+impl<'a, A, B> SortedView<'a> for SetView<A, B>
+where
+    A: SortedView<'a>,
+    B: SortedView<'a, Item = A::Item>,
+{
+}
+
+/// Iterator of [`SetView`].
+pub struct SetIter<A: Iterator, B: Iterator> {
+    a: Peekable<A>,
+    b: Peekable<B>,
+    op: SetOp,
+}
+
+impl<A, B> Iterator for SetIter<A, B>
+where
+    A: Iterator<Item: Ord>,
+    B: Iterator<Item = A::Item>,
+{
+    type Item = A::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        use SetOp::*;
+
+        loop {
+            let ord = match (self.a.peek(), self.b.peek()) {
+                (Some(a), Some(b)) => a.cmp(b),
+                (Some(_), None) if self.op != Intersection => Ordering::Less,
+                (None, Some(_)) if matches!(self.op, Union | SymmetricDifference) => {
+                    Ordering::Greater
+                }
+                _ => return None,
+            };
+            match ord {
+                Ordering::Less => {
+                    let a = self.a.next();
+                    if self.op != Intersection {
+                        return a;
+                    }
+                }
+                Ordering::Greater => {
+                    let b = self.b.next();
+                    if matches!(self.op, Union | SymmetricDifference) {
+                        return b;
+                    }
+                }
+                Ordering::Equal => {
+                    let a = self.a.next();
+                    self.b.next();
+                    if matches!(self.op, Union | Intersection) {
+                        return a;
+                    }
+                }
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (a_lo, a_hi) = self.a.size_hint();
+        let (b_lo, b_hi) = self.b.size_hint();
+        match self.op {
+            SetOp::Union => (
+                a_lo.max(b_lo),
+                a_hi.zip(b_hi).and_then(|(a, b)| a.checked_add(b)),
+            ),
+            SetOp::Intersection => (0, a_hi.min(b_hi).or(a_hi).or(b_hi)),
+            SetOp::Difference => (a_lo.saturating_sub(b_hi.unwrap_or(usize::MAX)), a_hi),
+            SetOp::SymmetricDifference => (0, a_hi.zip(b_hi).and_then(|(a, b)| a.checked_add(b))),
+        }
+    }
+}
+
+/// Merges the sorted views of `views` into one sorted view, keeping equal items, in O(n log k).
+///
+/// Equal items are yielded in the order of their views in `views`.
+#[cfg(feature = "alloc")]
+pub fn merge<'a, O, U>(views: O) -> MergeView<O>
+where
+    O: IterView<'a, Item = &'a U>,
+    U: SortedView<'a> + ?Sized + 'a,
+{
+    MergeView { views }
+}
+
+/// View created by [`merge()`].
+#[cfg(feature = "alloc")]
+#[derive(Clone, Copy, Debug)]
+pub struct MergeView<O> {
+    views: O,
+}
+
+#[cfg(feature = "alloc")]
+impl<'a, O, U> IterView<'a> for MergeView<O>
+where
+    O: IterView<'a, Item = &'a U>,
+    U: SortedView<'a> + ?Sized + 'a,
+{
+    type Item = U::Item;
+    type Iter = MergeIter<U::Iter>;
+    fn iter(&'a self) -> Self::Iter {
+        let heap = self
+            .views
+            .iter()
+            .enumerate()
+            .filter_map(|(index, view)| {
+                let mut iter = view.iter();
+                let item = iter.next()?;
+                Some(Head { item, index, iter })
+            })
+            .collect();
+        MergeIter { heap }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a, O, U> SortedView<'a> for MergeView<O>
+where
+    O: IterView<'a, Item = &'a U>,
+    U: SortedView<'a> + ?Sized + 'a,
+{
+}
+
+/// Iterator of [`MergeView`].
+#[cfg(feature = "alloc")]
+pub struct MergeIter<I: Iterator> {
+    heap: BinaryHeap<Head<I>>,
+}
+
+/// Next item of one of the merged iterators, ordered reversely to make `BinaryHeap` a min-heap.
+#[cfg(feature = "alloc")]
+struct Head<I: Iterator> {
+    item: I::Item,
+    index: usize,
+    iter: I,
+}
+
+#[cfg(feature = "alloc")]
+impl<I: Iterator<Item: Ord>> Ord for Head<I> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .item
+            .cmp(&self.item)
+            .then(other.index.cmp(&self.index))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<I: Iterator<Item: Ord>> PartialOrd for Head<I> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<I: Iterator<Item: Ord>> PartialEq for Head<I> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<I: Iterator<Item: Ord>> Eq for Head<I> {}
+
+#[cfg(feature = "alloc")]
+impl<I: Iterator<Item: Ord>> Iterator for MergeIter<I> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut head = self.heap.peek_mut()?;
+        match head.iter.next() {
+            Some(next) => Some(core::mem::replace(&mut head.item, next)),
+            None => Some(PeekMut::pop(head).item),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.heap.iter().fold((0, Some(0)), |(lo, hi), head| {
+            let (l, h) = head.iter.size_hint();
+            let hi = hi
+                .zip(h)
+                .and_then(|(a, b)| a.checked_add(b)?.checked_add(1));
+            (lo.saturating_add(l).saturating_add(1), hi)
+        })
+    }
+}
+
+/// Item of [`merge_join_by()`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum EitherOrBoth<L, R> {
+    Left(L),
+    Right(R),
+    Both(L, R),
+}
+
+/// Joins two views sorted by `cmp`, items of both views comparing equal are paired in
+/// [`EitherOrBoth::Both`].
+///
+/// Such as a full outer join of two lists sorted by key:
+///
+/// ```rust
+/// use iter_view::{merge_join_by, EitherOrBoth, IterView};
+///
+/// let a = [(1, "a"), (2, "b")];
+/// let b = [(2, 20), (3, 30)];
+/// let joined = merge_join_by(&a, &b, |(x, _), (y, _)| x.cmp(y));
+/// let keys: Vec<_> = joined
+///     .iter()
+///     .map(|v| match v {
+///         EitherOrBoth::Left((k, _)) | EitherOrBoth::Right((k, _)) => (*k, false),
+///         EitherOrBoth::Both((k, _), _) => (*k, true),
+///     })
+///     .collect();
+/// assert_eq!(keys, [(1, false), (2, true), (3, false)]);
+/// ```
+pub fn merge_join_by<'a, A, B, F>(a: A, b: B, cmp: F) -> MergeJoinView<A, B, F>
+where
+    A: IterView<'a>,
+    B: IterView<'a>,
+    F: Fn(&A::Item, &B::Item) -> Ordering,
+{
+    MergeJoinView { a, b, cmp }
+}
+
+/// View created by [`merge_join_by()`].
+#[derive(Clone, Copy, Debug)]
+pub struct MergeJoinView<A, B, F> {
+    a: A,
+    b: B,
+    cmp: F,
+}
+
+impl<'a, A, B, F> IterView<'a> for MergeJoinView<A, B, F>
+where
+    A: IterView<'a>,
+    B: IterView<'a>,
+    F: Fn(&A::Item, &B::Item) -> Ordering + 'a,
+{
+    type Item = EitherOrBoth<A::Item, B::Item>;
+    type Iter = MergeJoinIter<'a, A::Iter, B::Iter, F>;
+    fn iter(&'a self) -> Self::Iter {
+        MergeJoinIter {
+            a: self.a.iter().peekable(),
+            b: self.b.iter().peekable(),
+            cmp: &self.cmp,
+        }
+    }
+}
+
+/// Iterator of [`MergeJoinView`].
+pub struct MergeJoinIter<'a, A: Iterator, B: Iterator, F> {
+    a: Peekable<A>,
+    b: Peekable<B>,
+    cmp: &'a F,
+}
+
+impl<A, B, F> Iterator for MergeJoinIter<'_, A, B, F>
+where
+    A: Iterator,
+    B: Iterator,
+    F: Fn(&A::Item, &B::Item) -> Ordering,
+{
+    type Item = EitherOrBoth<A::Item, B::Item>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let ord = match (self.a.peek(), self.b.peek()) {
+            (Some(a), Some(b)) => (self.cmp)(a, b),
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => return None,
+        };
+        Some(match ord {
+            Ordering::Less => EitherOrBoth::Left(self.a.next()?),
+            Ordering::Greater => EitherOrBoth::Right(self.b.next()?),
+            Ordering::Equal => EitherOrBoth::Both(self.a.next()?, self.b.next()?),
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (a_lo, a_hi) = self.a.size_hint();
+        let (b_lo, b_hi) = self.b.size_hint();
+        (
+            a_lo.max(b_lo),
+            a_hi.zip(b_hi).and_then(|(a, b)| a.checked_add(b)),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn set_algebra() {
+        use crate::{RangeView, ViewExt};
+
+        let a = BTreeSet::from([1, 3, 5, 7]);
+        let b = vec![3, 4, 5];
+        let b = try_sorted(&b).unwrap();
+        let collect = |v: SetView<_, _>| v.iter().copied().collect::<Vec<i32>>();
+        assert_eq!(collect(union(&a, &b)), [1, 3, 4, 5, 7]);
+        assert_eq!(collect(intersection(&a, &b)), [3, 5]);
+        assert_eq!(collect(difference(&a, &b)), [1, 7]);
+        assert_eq!(collect(symmetric_difference(&a, &b)), [1, 4, 7]);
+
+        let evens = (&a).filter_view(|v| **v > 3);
+        let view = union(evens, a.range_view(..2));
+        assert_eq!(view.iter().collect::<Vec<_>>(), [&1, &5, &7]);
+        assert_eq!(view.iter().collect::<Vec<_>>(), [&1, &5, &7]);
+    }
+
+    #[test]
+    fn sorted_search() {
+        let v = [1, 3, 3, 8];
+        assert!(try_sorted(&[2, 1]).is_none());
+        let s = assume_sorted(&v);
+        assert_eq!(s.binary_search(&&8), Ok(3));
+        assert_eq!(s.binary_search(&&2), Err(1));
+        assert!(s.contains(&&3));
+        assert!(!s.contains(&&4));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn merge_views() {
+        let views = vec![vec![1, 4, 7], vec![], vec![2, 4, 8], vec![0]];
+        let views: Vec<_> = views.iter().map(assume_sorted).collect();
+        let merged = merge(&views);
+        let expected = [0, 1, 2, 4, 4, 7, 8];
+        assert_eq!(merged.iter().copied().collect::<Vec<_>>(), expected);
+        assert_eq!(merged.iter().size_hint(), (7, Some(7)));
+        let sets = [BTreeSet::from([3, 1]), BTreeSet::from([2])];
+        assert_eq!(merge(&sets).iter().collect::<Vec<_>>(), [&1, &2, &3]);
+    }
+}
@@ -0,0 +1,139 @@
+//! Async flavor of [`IterView`], enabled by the `stream` feature.
+
+use core::marker::PhantomData;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+use futures_core::Stream;
+
+use crate::IterView;
+
+/// Like `IterView`, but return a `Stream`, tasks can stream over the same value independently.
+///
+/// Every `IterView` is a `StreamView` of an always ready stream.
+pub trait StreamView<'a> {
+    type Item: 'a;
+    type Stream: Stream<Item = Self::Item>;
+    fn stream(&'a self) -> Self::Stream;
+}
+
+impl<'a, V: IterView<'a> + ?Sized> StreamView<'a> for V {
+    type Item = V::Item;
+    type Stream = IterStream<V::Iter>;
+    fn stream(&'a self) -> Self::Stream {
+        IterStream::new(self.iter())
+    }
+}
+
+/// Stream of the items of an iterator, always ready.
+#[derive(Clone, Debug)]
+pub struct IterStream<I> {
+    iter: I,
+}
+
+impl<I> IterStream<I> {
+    pub fn new(iter: I) -> Self {
+        Self { iter }
+    }
+}
+
+// The iterator is never pinned.
+impl<I> Unpin for IterStream<I> {}
+
+impl<I: Iterator> Stream for IterStream<I> {
+    type Item = I::Item;
+
+    fn poll_next(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        Poll::Ready(self.get_mut().iter.next())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+/// Using a function to stream view a value, async counterpart of [`iter()`](crate::iter()).
+pub fn stream<T, O, F, S>(o: &O, f: F) -> FuncStreamView<'_, T, O, F, S> {
+    FuncStreamView {
+        f,
+        o,
+        t: PhantomData,
+        s: PhantomData,
+    }
+}
+
+pub struct FuncStreamView<'a, T, O, F, S> {
+    f: F,
+    o: &'a O,
+    t: PhantomData<T>,
+    s: PhantomData<S>,
+}
+
+impl<'a, T, O, F, S> StreamView<'a> for FuncStreamView<'a, T, O, F, S>
+where
+    T: 'a,
+    F: Fn(&O) -> S,
+    S: Stream<Item = T> + 'a,
+{
+    type Item = T;
+    type Stream = S;
+    fn stream(&self) -> Self::Stream {
+        (self.f)(self.o)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::task::Waker;
+
+    fn block_collect<S: Stream>(s: S) -> Vec<S::Item> {
+        let mut s = std::pin::pin!(s);
+        let mut cx = Context::from_waker(Waker::noop());
+        let mut items = Vec::new();
+        loop {
+            match s.as_mut().poll_next(&mut cx) {
+                Poll::Ready(Some(item)) => items.push(item),
+                Poll::Ready(None) => return items,
+                Poll::Pending => {}
+            }
+        }
+    }
+
+    /// Counts down, pending once before each item.
+    struct Countdown {
+        n: u32,
+        ready: bool,
+    }
+
+    impl Stream for Countdown {
+        type Item = u32;
+
+        fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<u32>> {
+            self.ready = !self.ready;
+            if !self.ready {
+                cx.waker().wake_by_ref();
+                return Poll::Pending;
+            }
+            if self.n == 0 {
+                return Poll::Ready(None);
+            }
+            self.n -= 1;
+            Poll::Ready(Some(self.n))
+        }
+    }
+
+    #[test]
+    fn stream_vec() {
+        let v = vec![1, 2, 3];
+        assert_eq!(block_collect(v.stream()), [&1, &2, &3]);
+        assert_eq!(block_collect(v.stream()), [&1, &2, &3]);
+    }
+
+    #[test]
+    fn stream_func() {
+        let view = stream(&3, |n: &u32| Countdown { n: *n, ready: true });
+        assert_eq!(block_collect(view.stream()), [2, 1, 0]);
+        assert_eq!(block_collect(view.stream()), [2, 1, 0]);
+    }
+}
@@ -0,0 +1,472 @@
+//! Traversal views over trees, such as an AST whose nodes keep their children in a `Vec`.
+//!
+//! The walks keep an explicit stack or queue instead of recursing, deep trees don't overflow the
+//! call stack.
+
+use alloc::{collections::VecDeque, vec::Vec};
+
+use crate::IterView;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Traversal {
+    PreOrder,
+    PostOrder,
+    BreadthFirst,
+}
+
+/// Walks the tree of `root` depth first, every node is yielded before its children.
+///
+/// `children` returns the children of a node, as anything iterable over node references, such as
+/// `&Vec<Node>` or an iterator adapting the child links:
+///
+/// ```rust
+/// use iter_view::{pre_order, IterView};
+///
+/// struct Node {
+///     name: &'static str,
+///     children: Vec<Node>,
+/// }
+///
+/// let leaf = |name| Node { name, children: vec![] };
+/// let root = Node { name: "a", children: vec![leaf("b"), leaf("c")] };
+/// let view = pre_order(&root, |n: &Node| &n.children);
+/// let names: Vec<_> = view.iter().map(|n| n.name).collect();
+/// assert_eq!(names, ["a", "b", "c"]);
+/// ```
+///
+/// Children kept in a type that only implements `IterView`, such as a `#[derive(IterView)]`
+/// newtype, are wrapped in [`AsIntoIter`](crate::AsIntoIter): `|n| AsIntoIter(&n.children)`.
+pub fn pre_order<'a, N, I, F>(root: &'a N, children: F) -> TreeView<'a, N, F>
+where
+    F: Fn(&'a N) -> I,
+    I: IntoIterator<Item = &'a N>,
+{
+    TreeView::new(root, children, Traversal::PreOrder)
+}
+
+/// Walks the tree of `root` depth first, every node is yielded after its children.
+pub fn post_order<'a, N, I, F>(root: &'a N, children: F) -> TreeView<'a, N, F>
+where
+    F: Fn(&'a N) -> I,
+    I: IntoIterator<Item = &'a N>,
+{
+    TreeView::new(root, children, Traversal::PostOrder)
+}
+
+/// Walks the tree of `root` level by level.
+pub fn breadth_first<'a, N, I, F>(root: &'a N, children: F) -> TreeView<'a, N, F>
+where
+    F: Fn(&'a N) -> I,
+    I: IntoIterator<Item = &'a N>,
+{
+    TreeView::new(root, children, Traversal::BreadthFirst)
+}
+
+/// View created by [`pre_order()`], [`post_order()`] and [`breadth_first()`].
+#[derive(Clone, Copy, Debug)]
+pub struct TreeView<'a, N, F> {
+    root: &'a N,
+    children: F,
+    order: Traversal,
+}
+
+impl<'a, N, F> TreeView<'a, N, F> {
+    fn new(root: &'a N, children: F, order: Traversal) -> Self {
+        Self {
+            root,
+            children,
+            order,
+        }
+    }
+
+    /// Yields `(depth, node)`, the depth of the root is 0.
+    pub fn with_depth(self) -> TreeDepthView<'a, N, F> {
+        TreeDepthView(self)
+    }
+
+    /// Yields the path of each node, from the root to the node itself.
+    ///
+    /// Each path is a new `Vec`. Breadth first walks keep every visited node to rebuild the paths.
+    pub fn with_path(self) -> TreePathView<'a, N, F> {
+        TreePathView(self)
+    }
+
+    fn walk<I>(&'a self, keep_parents: bool) -> TreeIter<'a, N, I, F>
+    where
+        F: Fn(&'a N) -> I + 'a,
+        I: IntoIterator<Item = &'a N>,
+    {
+        let walk = match self.order {
+            Traversal::PreOrder | Traversal::PostOrder => Walk::DepthFirst {
+                stack: Vec::new(),
+                root: Some(self.root),
+                post_order: self.order == Traversal::PostOrder,
+                pop_pending: false,
+            },
+            Traversal::BreadthFirst => Walk::BreadthFirst {
+                queue: VecDeque::from([(0, self.root, None)]),
+                parents: Vec::new(),
+                keep_parents,
+            },
+        };
+        TreeIter {
+            children: &self.children,
+            walk,
+        }
+    }
+}
+
+impl<'a, N, F, I> IterView<'a> for TreeView<'a, N, F>
+where
+    F: Fn(&'a N) -> I + 'a,
+    I: IntoIterator<Item = &'a N>,
+{
+    type Item = &'a N;
+    type Iter = TreeIter<'a, N, I, F>;
+    fn iter(&'a self) -> Self::Iter {
+        self.walk(false)
+    }
+}
+
+/// View created by [`TreeView::with_depth()`].
+#[derive(Clone, Copy, Debug)]
+pub struct TreeDepthView<'a, N, F>(TreeView<'a, N, F>);
+
+impl<'a, N, F, I> IterView<'a> for TreeDepthView<'a, N, F>
+where
+    F: Fn(&'a N) -> I + 'a,
+    I: IntoIterator<Item = &'a N>,
+{
+    type Item = (usize, &'a N);
+    type Iter = TreeDepthIter<'a, N, I, F>;
+    fn iter(&'a self) -> Self::Iter {
+        TreeDepthIter(self.0.walk(false))
+    }
+}
+
+/// View created by [`TreeView::with_path()`].
+#[derive(Clone, Copy, Debug)]
+pub struct TreePathView<'a, N, F>(TreeView<'a, N, F>);
+
+impl<'a, N, F, I> IterView<'a> for TreePathView<'a, N, F>
+where
+    F: Fn(&'a N) -> I + 'a,
+    I: IntoIterator<Item = &'a N>,
+{
+    type Item = Vec<&'a N>;
+    type Iter = TreePathIter<'a, N, I, F>;
+    fn iter(&'a self) -> Self::Iter {
+        TreePathIter(self.0.walk(true))
+    }
+}
+
+enum Walk<'a, N, C> {
+    /// Ancestors of the current node with their remaining children, the current node on top.
+    DepthFirst {
+        stack: Vec<(&'a N, C)>,
+        root: Option<&'a N>,
+        post_order: bool,
+        /// The top of `stack` was yielded by post-order walk, pop it on next advance.
+        pop_pending: bool,
+    },
+    /// `(depth, node, index of parent in parents)`, `parents` is only filled for paths.
+    BreadthFirst {
+        queue: VecDeque<(usize, &'a N, Option<usize>)>,
+        parents: Vec<(&'a N, Option<usize>)>,
+        keep_parents: bool,
+    },
+}
+
+/// Iterator of [`TreeView`].
+pub struct TreeIter<'a, N, I: IntoIterator, F> {
+    children: &'a F,
+    walk: Walk<'a, N, I::IntoIter>,
+}
+
+impl<'a, N, I, F> TreeIter<'a, N, I, F>
+where
+    F: Fn(&'a N) -> I,
+    I: IntoIterator<Item = &'a N>,
+{
+    /// Moves to the next node, returns it with its depth.
+    fn advance(&mut self) -> Option<(usize, &'a N)> {
+        let children = self.children;
+        match &mut self.walk {
+            Walk::DepthFirst {
+                stack,
+                root,
+                post_order,
+                pop_pending,
+            } => {
+                if *pop_pending {
+                    stack.pop();
+                    *pop_pending = false;
+                }
+                if let Some(root) = root.take() {
+                    stack.push((root, children(root).into_iter()));
+                    if !*post_order {
+                        return Some((0, root));
+                    }
+                }
+                loop {
+                    let (node, iter) = stack.last_mut()?;
+                    let node = *node;
+                    match iter.next() {
+                        Some(child) => {
+                            stack.push((child, children(child).into_iter()));
+                            if !*post_order {
+                                return Some((stack.len() - 1, child));
+                            }
+                        }
+                        None if *post_order => {
+                            *pop_pending = true;
+                            return Some((stack.len() - 1, node));
+                        }
+                        None => {
+                            stack.pop();
+                        }
+                    }
+                }
+            }
+            Walk::BreadthFirst {
+                queue,
+                parents,
+                keep_parents,
+            } => {
+                let (depth, node, parent) = queue.pop_front()?;
+                let index = keep_parents.then(|| {
+                    parents.push((node, parent));
+                    parents.len() - 1
+                });
+                queue.extend(children(node).into_iter().map(|c| (depth + 1, c, index)));
+                Some((depth, node))
+            }
+        }
+    }
+
+    /// Path from the root to the node returned by the last `advance()`.
+    fn path(&self) -> Vec<&'a N> {
+        match &self.walk {
+            Walk::DepthFirst { stack, .. } => stack.iter().map(|(node, _)| *node).collect(),
+            Walk::BreadthFirst { parents, .. } => {
+                let mut path = Vec::new();
+                let mut index = parents.len().checked_sub(1);
+                while let Some(i) = index {
+                    let (node, parent) = parents[i];
+                    path.push(node);
+                    index = parent;
+                }
+                path.reverse();
+                path
+            }
+        }
+    }
+}
+
+impl<'a, N, I, F> Iterator for TreeIter<'a, N, I, F>
+where
+    F: Fn(&'a N) -> I,
+    I: IntoIterator<Item = &'a N>,
+{
+    type Item = &'a N;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.advance().map(|(_, node)| node)
+    }
+}
+
+/// Iterator of [`TreeDepthView`].
+pub struct TreeDepthIter<'a, N, I: IntoIterator, F>(TreeIter<'a, N, I, F>);
+
+impl<'a, N, I, F> Iterator for TreeDepthIter<'a, N, I, F>
+where
+    F: Fn(&'a N) -> I,
+    I: IntoIterator<Item = &'a N>,
+{
+    type Item = (usize, &'a N);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.advance()
+    }
+}
+
+/// Iterator of [`TreePathView`].
+pub struct TreePathIter<'a, N, I: IntoIterator, F>(TreeIter<'a, N, I, F>);
+
+impl<'a, N, I, F> Iterator for TreePathIter<'a, N, I, F>
+where
+    F: Fn(&'a N) -> I,
+    I: IntoIterator<Item = &'a N>,
+{
+    type Item = Vec<&'a N>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.advance()?;
+        Some(self.0.path())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Node {
+        id: u32,
+        children: Vec<Node>,
+    }
+
+    fn node(id: u32, children: Vec<Node>) -> Node {
+        Node { id, children }
+    }
+
+    //     1
+    //   2   5
+    //  3 4   6
+    fn tree() -> Node {
+        node(
+            1,
+            vec![
+                node(2, vec![node(3, vec![]), node(4, vec![])]),
+                node(5, vec![node(6, vec![])]),
+            ],
+        )
+    }
+
+    fn children(n: &Node) -> &Vec<Node> {
+        &n.children
+    }
+
+    #[test]
+    fn orders() {
+        let root = tree();
+        let ids = |v: &TreeView<'_, Node, _>| v.iter().map(|n| n.id).collect::<Vec<_>>();
+        let view = pre_order(&root, children);
+        assert_eq!(ids(&view), [1, 2, 3, 4, 5, 6]);
+        assert_eq!(ids(&view), [1, 2, 3, 4, 5, 6]);
+        assert_eq!(ids(&post_order(&root, children)), [3, 4, 2, 6, 5, 1]);
+        assert_eq!(ids(&breadth_first(&root, children)), [1, 2, 5, 3, 4, 6]);
+    }
+
+    #[test]
+    fn depth_and_path() {
+        let root = tree();
+        let depths =
+            |v: TreeDepthView<'_, Node, _>| v.iter().map(|(d, n)| (d, n.id)).collect::<Vec<_>>();
+        let expected = [(0, 1), (1, 2), (2, 3), (2, 4), (1, 5), (2, 6)];
+        assert_eq!(depths(pre_order(&root, children).with_depth()), expected);
+        let expected = [(2, 3), (2, 4), (1, 2), (2, 6), (1, 5), (0, 1)];
+        assert_eq!(depths(post_order(&root, children).with_depth()), expected);
+        let expected = [(0, 1), (1, 2), (1, 5), (2, 3), (2, 4), (2, 6)];
+        assert_eq!(
+            depths(breadth_first(&root, children).with_depth()),
+            expected
+        );
+
+        let paths = |v: TreePathView<'_, Node, _>| {
+            v.iter()
+                .map(|p| p.iter().map(|n| n.id).collect::<Vec<_>>())
+                .collect::<Vec<_>>()
+        };
+        let pre = paths(pre_order(&root, children).with_path());
+        assert_eq!(pre[3], [1, 2, 4]);
+        let post = paths(post_order(&root, children).with_path());
+        assert_eq!(post[2], [1, 2]);
+        let bfs = paths(breadth_first(&root, children).with_path());
+        assert_eq!(bfs[5], [1, 5, 6]);
+    }
+
+    #[test]
+    fn child_iterators() {
+        // The `Vec<Box<_>>` shape is common in trees built by other crates, such as parsers.
+        #[allow(clippy::vec_box)]
+        struct Boxed {
+            id: u32,
+            children: Vec<Box<Boxed>>,
+        }
+        let leaf = |id| {
+            Box::new(Boxed {
+                id,
+                children: vec![],
+            })
+        };
+        let root = Boxed {
+            id: 1,
+            children: vec![leaf(2), leaf(3)],
+        };
+        let view = pre_order(&root, |n: &Boxed| n.children.iter().map(|c| &**c));
+        assert_eq!(view.iter().map(|n| n.id).collect::<Vec<_>>(), [1, 2, 3]);
+
+        struct Binary {
+            id: u32,
+            left: Option<Box<Binary>>,
+            right: Option<Box<Binary>>,
+        }
+        let leaf = |id| {
+            Some(Box::new(Binary {
+                id,
+                left: None,
+                right: None,
+            }))
+        };
+        let root = Binary {
+            id: 2,
+            left: leaf(1),
+            right: leaf(3),
+        };
+        let view = post_order(&root, |n: &Binary| {
+            n.left.as_deref().into_iter().chain(n.right.as_deref())
+        });
+        assert_eq!(view.iter().map(|n| n.id).collect::<Vec<_>>(), [1, 3, 2]);
+
+        let root = tree();
+        let view = breadth_first(&root, |n: &Node| {
+            n.children.iter().filter(|c| c.id % 2 == 1)
+        });
+        let ids: Vec<_> = view.iter().map(|n| n.id).collect();
+        assert_eq!(ids, [1, 5]);
+    }
+
+    #[test]
+    fn view_children() {
+        use crate::AsIntoIter;
+
+        struct Children(Vec<Viewed>);
+        impl<'a> IterView<'a> for Children {
+            type Item = &'a Viewed;
+            type Iter = core::slice::Iter<'a, Viewed>;
+            fn iter(&'a self) -> Self::Iter {
+                self.0.iter()
+            }
+        }
+        struct Viewed {
+            id: u32,
+            children: Children,
+        }
+        let leaf = |id| Viewed {
+            id,
+            children: Children(vec![]),
+        };
+        let root = Viewed {
+            id: 1,
+            children: Children(vec![leaf(2), leaf(3)]),
+        };
+        let view = pre_order(&root, |n: &Viewed| AsIntoIter(&n.children));
+        assert_eq!(view.iter().map(|n| n.id).collect::<Vec<_>>(), [1, 2, 3]);
+    }
+
+    #[test]
+    fn deep_tree() {
+        let mut root = node(0, vec![]);
+        for id in 1..100_000 {
+            root = node(id, vec![root]);
+        }
+        assert_eq!(pre_order(&root, children).iter().count(), 100_000);
+        let view = post_order(&root, children).with_depth();
+        let (depth, leaf) = view.iter().next().unwrap();
+        assert_eq!((depth, leaf.id), (99_999, 0));
+        // Drop iteratively, the derived drop of `Node` recurses.
+        while let Some(child) = root.children.pop() {
+            root = child;
+        }
+    }
+}